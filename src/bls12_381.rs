@@ -0,0 +1,114 @@
+pub mod fq;
+pub mod fq2;
+pub mod fr;
+pub mod pairing;
+
+use crate::curve::{Affine, CurveParams, Projective};
+use crate::engine::Engine;
+use crate::error::Result;
+use crate::fp12::Fp12;
+use crate::fp6::Fp6;
+
+pub use fq::Fq;
+pub use fq2::Fq2;
+pub use fr::Fr;
+pub use pairing::{G2Prepared, Gt};
+
+// |x| for the BLS parameter x = -0xd201000000010000
+pub const BLS_X: u64 = 0xd201000000010000;
+
+pub const BLS_X_IS_NEGATIVE: bool = true;
+
+// y^2 = x^3 + 4 over Fq
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct G1Params;
+
+impl CurveParams for G1Params {
+    type Base = Fq;
+
+    fn coeff_b() -> Fq {
+        Fq::new(4)
+    }
+
+    fn generator() -> (Fq, Fq) {
+        let x = Fq::from_limbs([
+            0xfb3af00adb22c6bb, 0x6c55e83ff97a1aef, 0xa14e3a3f171bac58,
+            0xc3688c4f9774b905, 0x2695638c4fa9ac0f, 0x17f1d3a73197d794,
+        ]);
+        let y = Fq::from_limbs([
+            0x0caa232946c5e7e1, 0xd03cc744a2888ae4, 0x00db18cb2c04b3ed,
+            0xfcf5e095d5d00af6, 0xa09e30ed741d8ae4, 0x08b3f481e3aaa0f1,
+        ]);
+        (x, y)
+    }
+}
+
+// M-type sextic twist y^2 = x^3 + 4 (1 + u) over Fq2
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct G2Params;
+
+impl CurveParams for G2Params {
+    type Base = Fq2;
+
+    fn coeff_b() -> Fq2 {
+        Fq2::new(Fq::new(4), Fq::new(4))
+    }
+
+    fn generator() -> (Fq2, Fq2) {
+        let x = Fq2::new(
+            Fq::from_limbs([
+                0xd48056c8c121bdb8, 0x0bac0326a805bbef, 0xb4510b647ae3d177,
+                0xc6e47ad4fa403b02, 0x260805272dc51051, 0x024aa2b2f08f0a91,
+            ]),
+            Fq::from_limbs([
+                0xe5ac7d055d042b7e, 0x334cf11213945d57, 0xb5da61bbdc7f5049,
+                0x596bd0d09920b61a, 0x7dacd3a088274f65, 0x13e02b6052719f60,
+            ]),
+        );
+        let y = Fq2::new(
+            Fq::from_limbs([
+                0xe193548608b82801, 0x923ac9cc3baca289, 0x6d429a695160d12c,
+                0xadfd9baa8cbdd3a7, 0x8cc9cdc6da2e351a, 0x0ce5d527727d6e11,
+            ]),
+            Fq::from_limbs([
+                0xaaa9075ff05f79be, 0x3f370d275cec1da1, 0x267492ab572e99ab,
+                0xcb3e287e85a763af, 0x32acd2b02bc28b99, 0x0606c4a02ea734cc,
+            ]),
+        );
+        (x, y)
+    }
+}
+
+// Fq6 = Fq2[v] / (v^3 - xi), Fq12 = Fq6[w] / (w^2 - v)
+pub type Fq6 = Fp6<fq2::Fq2Config>;
+pub type Fq12 = Fp12<fq2::Fq2Config>;
+
+pub type G1Affine = Affine<G1Params>;
+pub type G1Projective = Projective<G1Params>;
+pub type G2Affine = Affine<G2Params>;
+pub type G2Projective = Projective<G2Params>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Bls12_381;
+
+impl Engine for Bls12_381 {
+    const NAME: &'static str = "bls12_381";
+
+    type Fr = Fr;
+    type G1 = G1Params;
+    type G2 = G2Params;
+    type Gt = Gt;
+    type G2Prepared = G2Prepared;
+
+    fn pairing(p: &G1Affine, q: &G2Affine) -> Result<Gt> {
+        crate::pairing::pairing(p, q)
+    }
+
+    fn prepare_g2(q: &G2Affine) -> Result<G2Prepared> {
+        G2Prepared::try_from(q)
+    }
+
+    fn multi_pairing(pairs: &[(&G1Affine, &G2Prepared)]) -> Gt {
+        crate::pairing::multi_pairing(pairs)
+    }
+}
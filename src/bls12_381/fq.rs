@@ -0,0 +1,21 @@
+use crate::fp::{Fp, FpConfig};
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct FqConfig;
+
+impl FpConfig<6> for FqConfig {
+    // p = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab
+    const MODULUS: [u64; 6] = [
+        0xb9feffffffffaaab,
+        0x1eabfffeb153ffff,
+        0x6730d2a0f6b0f624,
+        0x64774b84f38512bf,
+        0x4b1ba7b6434bacd7,
+        0x1a0111ea397fe69a,
+    ];
+
+    const GENERATOR: u64 = 2;
+}
+
+// Base field of BLS12-381
+pub type Fq = Fp<FqConfig, 6>;
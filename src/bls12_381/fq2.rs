@@ -0,0 +1,35 @@
+use std::sync::OnceLock;
+
+use crate::fp2::{self, Fp2, Fp2Config};
+
+use super::fq::Fq;
+
+// (p - 1) / 6
+const MODULUS_MINUS_ONE_DIV_SIX: [u64; 6] = [
+    0x49aa7ffffffff1c7,
+    0x051caaaa72e35555,
+    0xe688231ad3c82906,
+    0xe613e1eb7deb831f,
+    0x0c849bf3b5e1f223,
+    0x045582fc5eeaa66f,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Fq2Config;
+
+impl Fp2Config for Fq2Config {
+    type Fp = Fq;
+
+    // xi = 1 + u
+    fn mul_by_nonresidue(value: Fq2) -> Fq2 {
+        Fq2::new(value.c0 - value.c1, value.c0 + value.c1)
+    }
+
+    fn frobenius_coeffs() -> &'static [Fq2; 12] {
+        static COEFFS: OnceLock<[Fq2; 12]> = OnceLock::new();
+        COEFFS.get_or_init(|| fp2::frobenius_coeffs(&MODULUS_MINUS_ONE_DIV_SIX))
+    }
+}
+
+// Fq2 = Fq[u] / (u^2 + 1)
+pub type Fq2 = Fp2<Fq2Config>;
@@ -0,0 +1,19 @@
+use crate::fp::{Fp, FpConfig};
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct FrConfig;
+
+impl FpConfig<4> for FrConfig {
+    // r = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+    const MODULUS: [u64; 4] = [
+        0xffffffff00000001,
+        0x53bda402fffe5bfe,
+        0x3339d80809a1d805,
+        0x73eda753299d7d48,
+    ];
+
+    const GENERATOR: u64 = 7;
+}
+
+// Scalar field of BLS12-381, the order of G1 and G2
+pub type Fr = Fp<FrConfig, 4>;
@@ -0,0 +1,116 @@
+use crate::field::Field;
+use crate::pairing::{self, exp_by_x, PairingConfig, TwistType};
+
+use super::fq2::Fq2Config;
+use super::{Bls12_381, Fq12, G1Params, G2Params, BLS_X, BLS_X_IS_NEGATIVE};
+
+pub type Gt = pairing::Gt<Bls12_381>;
+
+pub type G2Prepared = pairing::G2Prepared<Bls12_381>;
+
+impl PairingConfig for Bls12_381 {
+    type Fp2Config = Fq2Config;
+    type G1 = G1Params;
+    type G2 = G2Params;
+
+    const TWIST_TYPE: TwistType = TwistType::M;
+
+    const X: u64 = BLS_X;
+
+    const X_IS_NEGATIVE: bool = BLS_X_IS_NEGATIVE;
+
+    // The ate loop runs over x itself
+    const ATE_LOOP_COUNT: u128 = BLS_X as u128;
+
+    // Hayashida, Hayasaka and Teruya, https://eprint.iacr.org/2020/875
+    // raises to 3(p^4 - p^2 + 1) / r
+    fn final_exponentiation_hard_part(mut r: Fq12) -> Fq12 {
+        let mut y0 = r.square();
+        let mut y1 = exp_by_x::<Bls12_381>(r);
+        let mut y2 = r.conjugate();
+        y1 = y1 * y2;
+        y2 = exp_by_x::<Bls12_381>(y1);
+        y1 = y1.conjugate() * y2;
+        y2 = exp_by_x::<Bls12_381>(y1);
+        y1 = y1.frobenius_map(1) * y2;
+        r = r * y0;
+        y0 = exp_by_x::<Bls12_381>(y1);
+        y2 = exp_by_x::<Bls12_381>(y0);
+        y0 = y1.frobenius_map(2);
+        y1 = y1.conjugate() * y2 * y0;
+        r * y1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::fq::FqConfig;
+    use crate::bls12_381::fr::FrConfig;
+    use crate::bls12_381::{Fq, Fq2, Fr, G1Affine, G2Affine};
+    use crate::engine::Engine;
+    use crate::error::Error;
+    use crate::fp::FpConfig;
+    use crate::pairing::tests;
+
+    // (p^12 - 1) / r * 3, computed with Python's big integers
+    const FINAL_EXPONENT: &str = concat!(
+        "8ca592196587127a538fd40dc3e541f9dca04bb7dc671be77cf17715a2b2fe3bea73dfb468d8f473094aecb7",
+        "315a664019fbd84913caba6579c08fd42009fe1bd6fcbce15eacb2cf3218a165958cb8bfdae2d2d542072823",
+        "14fc0dea9d6ff3a07dbd34efb77b732ba5f994816e296a72928cfee133bdc3ca9412b984b9783d9c6aa81297",
+        "ab1cd294a502304773528bbae8706979f28efa0d355b0224e2513d6e4a5d3bb4dde0523678105d9167ff1323",
+        "d6e99ac312d8a7d762336370c4347bb5a7e405d6f3496b2dd38e722d4c1f3ac25e3167ec2cb543d69430c37c",
+        "2f98fcdd0dd36caa9f5aa7994cec31b24ed5e515911037b376e521070d29c9d56cfa8c3574363efb20f28c19",
+        "e4105ab99edd44084bd23725017931d6740bda71e5f07600ce6b407e543c4bc40bcd4c0b600e6c98003bf854",
+        "8986b14d9098746dc89d154af91ad54f337b31c79222145dd3ed254fdeda0300c49ebcd2352765f533883a35",
+        "13435f3ee452496f5166c25bf503bd6ec0a0679efda3b46ebf86211d458de749460d4a2a19abe6ea2accb451",
+        "ab9a096b98465d044dc2a7f86c253a4ee57b6df108eff598a8dbc483bf8b74c2789939db85ffd7e0fd55b32b",
+        "c26877f5be26fa7d750500ce2fab93c0cbe7336b126a5693d0c16484f37addccc7642590dbe98538990b8863",
+        "7e374d545d9b34b67448d0357e60280bbd8542f1f4e813caa8e8db57364b4e0cc14f35af381dd9b71ec9292b",
+        "3a3f16e42362d2019e05f30",
+    );
+
+    #[test]
+    fn generators_in_r_torsion() {
+        tests::generators_in_r_torsion::<Bls12_381>(&FrConfig::MODULUS);
+    }
+
+    #[test]
+    fn non_degenerate() {
+        tests::non_degenerate::<Bls12_381>(&FrConfig::MODULUS);
+    }
+
+    #[test]
+    fn bilinear() {
+        tests::bilinear::<Bls12_381>();
+    }
+
+    #[test]
+    fn frobenius_is_pow_p() {
+        tests::frobenius_is_pow_p::<Bls12_381>(&FqConfig::MODULUS);
+    }
+
+    #[test]
+    fn final_exponentiation_is_naive_pow() {
+        tests::final_exponentiation_is_naive_pow::<Bls12_381>(FINAL_EXPONENT);
+    }
+
+    // On the twist but of order 13, so T = -Q twelve steps into the Miller loop; computed with Python
+    #[test]
+    fn small_order_twist_point_is_rejected() {
+        let q = G2Affine::new(
+            Fq2::new(
+                Fq::from_limbs([0x56905843518bc0c2, 0x6bae16f9f2763ab5, 0x34d320e323d9fcc7, 0xffeef5b14a3e2468, 0x8340a1a548d700c5, 0x043ff79d06a80add]),
+                Fq::from_limbs([0xee69a68b7d4704a4, 0x278c22ea15e6d03c, 0x0a23c05406588284, 0x09b25206fa89dded, 0x7d4ebc6409c2fdae, 0x032762e5199990da]),
+            ),
+            Fq2::new(
+                Fq::from_limbs([0x4501e53fabc600f3, 0x67961c8d7bcc69b9, 0xb6502cc324ab0479, 0x0f05b83418b3d3b6, 0x916f6195da10e94d, 0x07b8eb3b0d94f08c]),
+                Fq::from_limbs([0x7eb47d01649f4c1e, 0x0a516e85dc3f7497, 0x1192186219d8d38a, 0xfefcf24fd6b08089, 0x02d3dda394c1dd5f, 0x082b2fb28bdddaf7]),
+            ),
+        );
+        assert!(q.is_on_curve());
+        assert!(!q.to_projective().is_in_subgroup::<Fr>());
+        assert!(matches!(Bls12_381::prepare_g2(&q), Err(Error::NonInvertible)));
+        assert!(matches!(Bls12_381::pairing(&G1Affine::generator(), &q), Err(Error::NonInvertible)));
+    }
+}
@@ -0,0 +1,94 @@
+pub mod fq;
+pub mod fq2;
+pub mod fr;
+pub mod pairing;
+
+use crate::curve::{Affine, CurveParams, Projective};
+use crate::engine::Engine;
+use crate::error::Result;
+use crate::fp12::Fp12;
+use crate::fp6::Fp6;
+use crate::field::Field;
+
+pub use fq::Fq;
+pub use fq2::Fq2;
+pub use fr::Fr;
+pub use pairing::{G2Prepared, Gt};
+
+// BN parameter x, p = 36x^4 + 36x^3 + 24x^2 + 6x + 1
+pub const BN_X: u64 = 4965661367192848881;
+
+// y^2 = x^3 + 3 over Fq
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct G1Params;
+
+impl CurveParams for G1Params {
+    type Base = Fq;
+
+    fn coeff_b() -> Fq {
+        Fq::new(3)
+    }
+
+    fn generator() -> (Fq, Fq) {
+        (Fq::new(1), Fq::new(2))
+    }
+}
+
+// D-type sextic twist y^2 = x^3 + 3 / (9 + u) over Fq2
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct G2Params;
+
+impl CurveParams for G2Params {
+    type Base = Fq2;
+
+    fn coeff_b() -> Fq2 {
+        let xi = Fq2::one().mul_by_nonresidue();
+        Fq2::new(Fq::new(3), Fq::zero()) * xi.inverse().unwrap()
+    }
+
+    fn generator() -> (Fq2, Fq2) {
+        let x = Fq2::new(
+            Fq::from_limbs([0x46debd5cd992f6ed, 0x674322d4f75edadd, 0x426a00665e5c4479, 0x1800deef121f1e76]),
+            Fq::from_limbs([0x97e485b7aef312c2, 0xf1aa493335a9e712, 0x7260bfb731fb5d25, 0x198e9393920d483a]),
+        );
+        let y = Fq2::new(
+            Fq::from_limbs([0x4ce6cc0166fa7daa, 0xe3d1e7690c43d37b, 0x4aab71808dcb408f, 0x12c85ea5db8c6deb]),
+            Fq::from_limbs([0x55acdadcd122975b, 0xbc4b313370b38ef3, 0xec9e99ad690c3395, 0x090689d0585ff075]),
+        );
+        (x, y)
+    }
+}
+
+// Fq6 = Fq2[v] / (v^3 - xi), Fq12 = Fq6[w] / (w^2 - v)
+pub type Fq6 = Fp6<fq2::Fq2Config>;
+pub type Fq12 = Fp12<fq2::Fq2Config>;
+
+pub type G1Affine = Affine<G1Params>;
+pub type G1Projective = Projective<G1Params>;
+pub type G2Affine = Affine<G2Params>;
+pub type G2Projective = Projective<G2Params>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Bn254;
+
+impl Engine for Bn254 {
+    const NAME: &'static str = "bn254";
+
+    type Fr = Fr;
+    type G1 = G1Params;
+    type G2 = G2Params;
+    type Gt = Gt;
+    type G2Prepared = G2Prepared;
+
+    fn pairing(p: &G1Affine, q: &G2Affine) -> Result<Gt> {
+        crate::pairing::pairing(p, q)
+    }
+
+    fn prepare_g2(q: &G2Affine) -> Result<G2Prepared> {
+        G2Prepared::try_from(q)
+    }
+
+    fn multi_pairing(pairs: &[(&G1Affine, &G2Prepared)]) -> Gt {
+        crate::pairing::multi_pairing(pairs)
+    }
+}
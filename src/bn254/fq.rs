@@ -0,0 +1,19 @@
+use crate::fp::{Fp, FpConfig};
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct FqConfig;
+
+impl FpConfig<4> for FqConfig {
+    // p = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+    const MODULUS: [u64; 4] = [
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ];
+
+    const GENERATOR: u64 = 3;
+}
+
+// Base field of BN254
+pub type Fq = Fp<FqConfig, 4>;
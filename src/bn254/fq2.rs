@@ -0,0 +1,34 @@
+use std::sync::OnceLock;
+
+use crate::fp2::{self, Fp2, Fp2Config};
+
+use super::fq::Fq;
+
+// (p - 1) / 6
+const MODULUS_MINUS_ONE_DIV_SIX: [u64; 4] = [
+    0x34b017592414d4e1,
+    0xee9591c2e6bda1c2,
+    0xf40d60f3c0403964,
+    0x0810b7bdd032f006,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Fq2Config;
+
+impl Fp2Config for Fq2Config {
+    type Fp = Fq;
+
+    // xi = 9 + u
+    fn mul_by_nonresidue(value: Fq2) -> Fq2 {
+        let nine = Fq::new(9);
+        Fq2::new(value.c0 * nine - value.c1, value.c0 + value.c1 * nine)
+    }
+
+    fn frobenius_coeffs() -> &'static [Fq2; 12] {
+        static COEFFS: OnceLock<[Fq2; 12]> = OnceLock::new();
+        COEFFS.get_or_init(|| fp2::frobenius_coeffs(&MODULUS_MINUS_ONE_DIV_SIX))
+    }
+}
+
+// Fq2 = Fq[u] / (u^2 + 1)
+pub type Fq2 = Fp2<Fq2Config>;
@@ -0,0 +1,19 @@
+use crate::fp::{Fp, FpConfig};
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct FrConfig;
+
+impl FpConfig<4> for FrConfig {
+    // r = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+    const MODULUS: [u64; 4] = [
+        0x43e1f593f0000001,
+        0x2833e84879b97091,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ];
+
+    const GENERATOR: u64 = 5;
+}
+
+// Scalar field of BN254, the order of G1 and G2
+pub type Fr = Fp<FrConfig, 4>;
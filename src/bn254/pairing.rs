@@ -0,0 +1,114 @@
+use crate::field::Field;
+use crate::fp2::Fp2;
+use crate::pairing::{self, exp_by_x, PairingConfig, TwistType};
+
+use super::fq2::{Fq2, Fq2Config};
+use super::{Bn254, Fq12, G1Params, G2Params, BN_X};
+
+pub type Gt = pairing::Gt<Bn254>;
+
+pub type G2Prepared = pairing::G2Prepared<Bn254>;
+
+impl PairingConfig for Bn254 {
+    type Fp2Config = Fq2Config;
+    type G1 = G1Params;
+    type G2 = G2Params;
+
+    const TWIST_TYPE: TwistType = TwistType::D;
+
+    const X: u64 = BN_X;
+
+    const X_IS_NEGATIVE: bool = false;
+
+    // 6x + 2, the optimal ate loop
+    const ATE_LOOP_COUNT: u128 = 29793968203157093288;
+
+    // Q1 = pi(Q), Q2 = -pi^2(Q)
+    fn final_additions(q: (Fq2, Fq2)) -> Vec<(Fq2, Fq2)> {
+        let gamma1 = Fp2::frobenius_coeff(1);
+        let gamma2 = Fp2::frobenius_coeff(2);
+        let q1 = (
+            q.0.conjugate() * gamma1.square(),
+            q.1.conjugate() * gamma1.square() * gamma1,
+        );
+        let q2 = (
+            q.0 * gamma2.square(),
+            -(q.1 * gamma2.square() * gamma2),
+        );
+        vec![q1, q2]
+    }
+
+    // Fuentes-Castaneda et al. "Faster hashing to G2",
+    // raises to 2x(6x^2 + 3x + 1)(p^4 - p^2 + 1) / r
+    fn final_exponentiation_hard_part(r: Fq12) -> Fq12 {
+        let y0 = exp_by_neg_x(r);
+        let y1 = y0.square();
+        let y2 = y1.square();
+        let y3 = y2 * y1;
+        let y4 = exp_by_neg_x(y3);
+        let y5 = y4.square();
+        let y6 = exp_by_neg_x(y5).conjugate();
+        let y3 = y3.conjugate();
+        let y7 = y6 * y4;
+        let y8 = y7 * y3;
+        let y9 = y8 * y1;
+        let y10 = y8 * y4;
+        let y11 = y10 * r;
+        let y12 = y9.frobenius_map(1);
+        let y13 = y12 * y11;
+        let y14 = y8.frobenius_map(2) * y13;
+        let y15 = (r.conjugate() * y9).frobenius_map(3);
+        y15 * y14
+    }
+}
+
+fn exp_by_neg_x(f: Fq12) -> Fq12 {
+    exp_by_x::<Bn254>(f).conjugate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::fq::FqConfig;
+    use crate::bn254::fr::FrConfig;
+    use crate::fp::FpConfig;
+    use crate::pairing::tests;
+
+    // (p^12 - 1) / r * 2x(6x^2 + 3x + 1), computed with Python's big integers
+    const FINAL_EXPONENT: &str = concat!(
+        "b12091dc4bd9ad132bcd28278a0eb11404cc4538560bfc996f315f361b5866c6aec784a2694465792b4c3f8c",
+        "1b59337ff61628bb6a263cc1785a63aeb9491aafcb243b9cf9a04cf66b6070c717cfc246d4988280b4b66ec5",
+        "d2d7e4493fa58f710fda4be6100a5b65c435164e22073bb80bbab9c2232fe62aef859bdb094534abcb4cb59b",
+        "b519574a861926412565d8cd0db6fb15fd484877cd01d3197dab4ae0cde0ad0bd7a43d444e031e1aad4a8c29",
+        "2bf4cfb4fdcb8cd82b389badcd5eae5572ad394def10c145c5643815e0fe344123f46a37789e86168f2dc3b6",
+        "245b0b16ce2fa52a61a3c5ccff0633ccfadca37ccfc4370e2bb3ba45c2a11d52979a938e4250828f8526944a",
+        "b5c6420efcc60d3b2451ca64cfe01315e736b15a5ed26ef2edfa24c279f1b01890f1d793979cb73667e67700",
+        "85cd89edadc7f6a24fb015e91736fc12d82caf72c53ce75a1e4ec10ae4fd7bd410cf22f374fe50166ada04fd",
+        "6195b1c8473245a17663153ca05213f41abb31280",
+    );
+
+    #[test]
+    fn generators_in_r_torsion() {
+        tests::generators_in_r_torsion::<Bn254>(&FrConfig::MODULUS);
+    }
+
+    #[test]
+    fn non_degenerate() {
+        tests::non_degenerate::<Bn254>(&FrConfig::MODULUS);
+    }
+
+    #[test]
+    fn bilinear() {
+        tests::bilinear::<Bn254>();
+    }
+
+    #[test]
+    fn frobenius_is_pow_p() {
+        tests::frobenius_is_pow_p::<Bn254>(&FqConfig::MODULUS);
+    }
+
+    #[test]
+    fn final_exponentiation_is_naive_pow() {
+        tests::final_exponentiation_is_naive_pow::<Bn254>(FINAL_EXPONENT);
+    }
+}
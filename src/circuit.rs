@@ -0,0 +1,51 @@
+use crate::constraint_system::{ConstraintSystem, Variable};
+use crate::error::{Error, Result};
+use crate::field::Field;
+use crate::r1cs::{LinearCombination, R1CS};
+
+// A circuit written once and synthesized twice: into a SetupSystem for the
+// trusted setup and into a ProvingSystem, with values, for the prover.
+// Values should be Options read inside the alloc closures, which setup never calls.
+pub trait Circuit<F: Field> {
+    fn synthesize<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<()>;
+}
+
+// A fixed R1CS carries no values, so it only synthesizes for setup;
+// prove it from a witness with prove_witness instead
+impl<F: Field> Circuit<F> for R1CS<F> {
+    fn synthesize<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<()> {
+        let mut variables = vec![Variable::one()];
+        for i in 1..self.num_variables() {
+            let variable = if i <= self.num_public() {
+                cs.alloc_input(|| Err(Error::AssignmentMissing))?
+            } else {
+                cs.alloc(|| Err(Error::AssignmentMissing))?
+            };
+            variables.push(variable);
+        }
+
+        let row_to_lc = |row: &[(usize, F)]| {
+            row.iter().fold(LinearCombination::zero(), |lc, &(i, coeff)| lc.term(variables[i], coeff))
+        };
+        let rows = self.left().rows().zip(self.right().rows()).zip(self.output().rows());
+        for (i, ((a, b), c)) in rows.enumerate() {
+            let (a, b, c) = (row_to_lc(a), row_to_lc(b), row_to_lc(c));
+            let Some(name) = self.name(i) else {
+                cs.enforce(a, b, c);
+                continue;
+            };
+            // Replays the namespaces so the constraint keeps its name
+            for segment in name.namespace() {
+                cs.push_namespace(segment);
+            }
+            match name.label() {
+                Some(label) => cs.enforce_named(label, a, b, c),
+                None => cs.enforce(a, b, c),
+            }
+            for _ in name.namespace() {
+                cs.pop_namespace();
+            }
+        }
+        Ok(())
+    }
+}
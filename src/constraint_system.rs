@@ -0,0 +1,306 @@
+use std::ops::Add;
+
+use crate::error::Result;
+use crate::field::Field;
+use crate::r1cs::{Constraint, ConstraintName, LinearCombination, R1CS};
+
+// Handle to a variable allocated by a ConstraintSystem
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Variable(Index);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Index {
+    One,
+    Public(usize),
+    Private(usize),
+}
+
+// What a circuit is synthesized into. Values are produced lazily, so the
+// same circuit code runs during setup, where no values exist, and proving.
+pub trait ConstraintSystem<F: Field> {
+    // A variable known to the verifier
+    fn alloc_input<A: FnOnce() -> Result<F>>(&mut self, value: A) -> Result<Variable>;
+
+    // A variable only the prover knows
+    fn alloc<A: FnOnce() -> Result<F>>(&mut self, value: A) -> Result<Variable>;
+
+    // a * b = c
+    fn enforce(
+        &mut self,
+        a: impl Into<LinearCombination<F, Variable>>,
+        b: impl Into<LinearCombination<F, Variable>>,
+        c: impl Into<LinearCombination<F, Variable>>,
+    );
+
+    // a * b = c, with a label R1CS::verify reports if it fails
+    fn enforce_named(
+        &mut self,
+        label: &str,
+        a: impl Into<LinearCombination<F, Variable>>,
+        b: impl Into<LinearCombination<F, Variable>>,
+        c: impl Into<LinearCombination<F, Variable>>,
+    );
+
+    fn push_namespace(&mut self, name: &str);
+
+    fn pop_namespace(&mut self);
+
+    // Runs f with name appended to the namespace path of every constraint it enforces
+    fn namespace<R>(&mut self, name: &str, f: impl FnOnce(&mut Self) -> R) -> R
+    where
+        Self: Sized,
+    {
+        self.push_namespace(name);
+        let result = f(self);
+        self.pop_namespace();
+        result
+    }
+}
+
+// Constraints still in terms of Variables, named after the namespaces open when enforced
+struct Recorder<F: Field> {
+    constraints: Vec<Constraint<F, Variable>>,
+    namespace: Vec<String>,
+}
+
+// Records only the shape of a circuit, for the trusted setup
+pub struct SetupSystem<F: Field> {
+    num_public: usize,
+    num_private: usize,
+    recorder: Recorder<F>,
+}
+
+// Records the shape and the assigned values, for the prover.
+// Public variables land right after the constant one however allocations are interleaved.
+pub struct ProvingSystem<F: Field> {
+    public: Vec<F>,
+    private: Vec<F>,
+    recorder: Recorder<F>,
+}
+
+impl Variable {
+    // The constant one, w0
+    pub fn one() -> Variable {
+        Variable(Index::One)
+    }
+}
+
+impl<F: Field> Recorder<F> {
+    fn new() -> Self {
+        Recorder { constraints: vec![], namespace: vec![] }
+    }
+
+    fn push(
+        &mut self,
+        label: Option<&str>,
+        a: LinearCombination<F, Variable>,
+        b: LinearCombination<F, Variable>,
+        c: LinearCombination<F, Variable>,
+    ) {
+        let mut constraint = Constraint::new(a, b, c);
+        if label.is_some() || !self.namespace.is_empty() {
+            constraint = constraint.with_name(ConstraintName::new(self.namespace.clone(), label.map(String::from)));
+        }
+        self.constraints.push(constraint);
+    }
+
+    // Numbers the variables as the witness layout [1, public..., private...]
+    fn finalize(self, num_public: usize, num_private: usize) -> Result<R1CS<F>> {
+        let index = |variable: Variable| match variable.0 {
+            Index::One => 0,
+            Index::Public(i) => 1 + i,
+            Index::Private(i) => 1 + num_public + i,
+        };
+        let constraints = self.constraints
+            .into_iter()
+            .map(|constraint| constraint.map(index))
+            .collect();
+        R1CS::new(constraints, 1 + num_public + num_private)?.with_public_inputs(num_public)
+    }
+}
+
+impl<F: Field> SetupSystem<F> {
+    pub fn new() -> Self {
+        SetupSystem { num_public: 0, num_private: 0, recorder: Recorder::new() }
+    }
+
+    pub fn num_constraints(&self) -> usize {
+        self.recorder.constraints.len()
+    }
+
+    pub fn finalize(self) -> Result<R1CS<F>> {
+        self.recorder.finalize(self.num_public, self.num_private)
+    }
+}
+
+impl<F: Field> Default for SetupSystem<F> {
+    fn default() -> Self {
+        SetupSystem::new()
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for SetupSystem<F> {
+    fn alloc_input<A: FnOnce() -> Result<F>>(&mut self, _value: A) -> Result<Variable> {
+        self.num_public += 1;
+        Ok(Variable(Index::Public(self.num_public - 1)))
+    }
+
+    fn alloc<A: FnOnce() -> Result<F>>(&mut self, _value: A) -> Result<Variable> {
+        self.num_private += 1;
+        Ok(Variable(Index::Private(self.num_private - 1)))
+    }
+
+    fn enforce(
+        &mut self,
+        a: impl Into<LinearCombination<F, Variable>>,
+        b: impl Into<LinearCombination<F, Variable>>,
+        c: impl Into<LinearCombination<F, Variable>>,
+    ) {
+        self.recorder.push(None, a.into(), b.into(), c.into());
+    }
+
+    fn enforce_named(
+        &mut self,
+        label: &str,
+        a: impl Into<LinearCombination<F, Variable>>,
+        b: impl Into<LinearCombination<F, Variable>>,
+        c: impl Into<LinearCombination<F, Variable>>,
+    ) {
+        self.recorder.push(Some(label), a.into(), b.into(), c.into());
+    }
+
+    fn push_namespace(&mut self, name: &str) {
+        self.recorder.namespace.push(name.to_string());
+    }
+
+    fn pop_namespace(&mut self) {
+        self.recorder.namespace.pop();
+    }
+}
+
+impl<F: Field> ProvingSystem<F> {
+    pub fn new() -> Self {
+        ProvingSystem { public: vec![], private: vec![], recorder: Recorder::new() }
+    }
+
+    pub fn value(&self, variable: Variable) -> F {
+        match variable.0 {
+            Index::One => F::one(),
+            Index::Public(i) => self.public[i],
+            Index::Private(i) => self.private[i],
+        }
+    }
+
+    pub fn num_constraints(&self) -> usize {
+        self.recorder.constraints.len()
+    }
+
+    // The R1CS with its public inputs declared, and the witness [1, public..., private...]
+    pub fn finalize(self) -> Result<(R1CS<F>, Vec<F>)> {
+        let r1cs = self.recorder.finalize(self.public.len(), self.private.len())?;
+        let witness = std::iter::once(F::one())
+            .chain(self.public)
+            .chain(self.private)
+            .collect();
+        Ok((r1cs, witness))
+    }
+}
+
+impl<F: Field> Default for ProvingSystem<F> {
+    fn default() -> Self {
+        ProvingSystem::new()
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for ProvingSystem<F> {
+    fn alloc_input<A: FnOnce() -> Result<F>>(&mut self, value: A) -> Result<Variable> {
+        self.public.push(value()?);
+        Ok(Variable(Index::Public(self.public.len() - 1)))
+    }
+
+    fn alloc<A: FnOnce() -> Result<F>>(&mut self, value: A) -> Result<Variable> {
+        self.private.push(value()?);
+        Ok(Variable(Index::Private(self.private.len() - 1)))
+    }
+
+    fn enforce(
+        &mut self,
+        a: impl Into<LinearCombination<F, Variable>>,
+        b: impl Into<LinearCombination<F, Variable>>,
+        c: impl Into<LinearCombination<F, Variable>>,
+    ) {
+        self.recorder.push(None, a.into(), b.into(), c.into());
+    }
+
+    fn enforce_named(
+        &mut self,
+        label: &str,
+        a: impl Into<LinearCombination<F, Variable>>,
+        b: impl Into<LinearCombination<F, Variable>>,
+        c: impl Into<LinearCombination<F, Variable>>,
+    ) {
+        self.recorder.push(Some(label), a.into(), b.into(), c.into());
+    }
+
+    fn push_namespace(&mut self, name: &str) {
+        self.recorder.namespace.push(name.to_string());
+    }
+
+    fn pop_namespace(&mut self) {
+        self.recorder.namespace.pop();
+    }
+}
+
+impl<F: Field> From<Variable> for LinearCombination<F, Variable> {
+    fn from(variable: Variable) -> Self {
+        LinearCombination::new(vec![(variable, F::one())])
+    }
+}
+
+impl<F: Field> Add<Variable> for LinearCombination<F, Variable> {
+    type Output = Self;
+
+    fn add(self, rhs: Variable) -> Self::Output {
+        self.term(rhs, F::one())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::Fr;
+    use crate::error::Error;
+
+    #[test]
+    fn failing_constraint_reports_its_namespace_and_values() {
+        let mut cs = ProvingSystem::<Fr>::new();
+        let x = cs.alloc_input(|| Ok(Fr::from_u64(3))).unwrap();
+        let y = cs.alloc(|| Ok(Fr::from_u64(4))).unwrap();
+        cs.enforce(x, Variable::one(), x);
+        cs.namespace("hash", |cs| {
+            cs.namespace("round 2", |cs| {
+                // 3 * 4 = 3 fails
+                cs.enforce_named("mix", x, y, LinearCombination::zero() + x);
+            });
+        });
+        cs.enforce(y, Variable::one(), y);
+
+        let (r1cs, witness) = cs.finalize().unwrap();
+        let unsatisfied = match r1cs.verify(&witness) {
+            Err(Error::UnsatisfiedConstraints(unsatisfied)) => unsatisfied,
+            other => panic!("expected an unsatisfied constraint, got {:?}", other.err()),
+        };
+        assert_eq!(unsatisfied.len(), 1);
+        let failing = &unsatisfied[0];
+        assert_eq!(failing.index, 1);
+        let name = failing.name.as_ref().unwrap();
+        assert_eq!(name.namespace(), ["hash", "round 2"]);
+        assert_eq!(name.label(), Some("mix"));
+        assert_eq!((failing.left.as_str(), failing.right.as_str(), failing.output.as_str()), ("3", "4", "3"));
+        assert_eq!(failing.to_string(), "constraint 1 (hash/round 2/mix): A·w = 3, B·w = 4, C·w = 3");
+
+        // Namespaces close with their closure
+        assert_eq!(r1cs.name(0), None);
+        assert_eq!(r1cs.name(2), None);
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::error::{Error, Result};
+use crate::field::{Field, PrimeField};
+
+// Short Weierstrass curve y^2 = x^3 + b
+pub trait CurveParams: Copy + Clone + Debug + PartialEq + Eq {
+    type Base: Field;
+
+    fn coeff_b() -> Self::Base;
+
+    fn generator() -> (Self::Base, Self::Base);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Affine<C: CurveParams> {
+    x: C::Base,
+    y: C::Base,
+    infinity: bool,
+}
+
+// Jacobian coordinates: (X, Y, Z) represents (X / Z^2, Y / Z^3), Z = 0 is the identity
+#[derive(Clone, Debug, Copy)]
+pub struct Projective<C: CurveParams> {
+    x: C::Base,
+    y: C::Base,
+    z: C::Base,
+}
+
+impl<C: CurveParams> Affine<C> {
+    pub fn new(x: C::Base, y: C::Base) -> Self {
+        Affine { x, y, infinity: false }
+    }
+
+    pub fn identity() -> Self {
+        Affine {
+            x: C::Base::zero(),
+            y: C::Base::one(),
+            infinity: true,
+        }
+    }
+
+    pub fn generator() -> Self {
+        let (x, y) = C::generator();
+        Affine::new(x, y)
+    }
+
+    pub fn x(&self) -> C::Base {
+        self.x
+    }
+
+    pub fn y(&self) -> C::Base {
+        self.y
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        if self.infinity {
+            return true;
+        }
+        let rhs = self.x.square() * self.x + C::coeff_b();
+        self.y.square() == rhs
+    }
+
+    pub fn negate(self) -> Affine<C> {
+        if self.infinity {
+            return self;
+        }
+        Affine::new(self.x, -self.y)
+    }
+
+    pub fn to_projective(self) -> Projective<C> {
+        if self.infinity {
+            return Projective::identity();
+        }
+        Projective {
+            x: self.x,
+            y: self.y,
+            z: C::Base::one(),
+        }
+    }
+}
+
+impl<C: CurveParams> Projective<C> {
+    pub fn identity() -> Self {
+        Projective {
+            x: C::Base::one(),
+            y: C::Base::one(),
+            z: C::Base::zero(),
+        }
+    }
+
+    pub fn generator() -> Self {
+        Affine::<C>::generator().to_projective()
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        self.to_affine().is_on_curve()
+    }
+
+    // [r]P = O for the prime r = |F|
+    pub fn is_in_subgroup<F: PrimeField>(&self) -> bool {
+        self.mul_limbs(&F::characteristic()).is_identity()
+    }
+
+    pub fn to_affine(self) -> Affine<C> {
+        let z_inv = match self.z.inverse() {
+            Some(z_inv) => z_inv,
+            None => return Affine::identity(),
+        };
+        let z_inv2 = z_inv.square();
+        Affine::new(self.x * z_inv2, self.y * z_inv2 * z_inv)
+    }
+
+    // dbl-2009-l, a = 0
+    pub fn double(self) -> Projective<C> {
+        if self.is_identity() || self.y.is_zero() {
+            return Projective::identity();
+        }
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = a.double() + a;
+        let f = e.square();
+        let x3 = f - d.double();
+        let y3 = e * (d - x3) - c.double().double().double();
+        let z3 = (self.y * self.z).double();
+        Projective { x: x3, y: y3, z: z3 }
+    }
+
+    // add-2007-bl
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Projective<C>) -> Projective<C> {
+        if self.is_identity() {
+            return other;
+        }
+        if other.is_identity() {
+            return self;
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+
+        if u1 == u2 {
+            if s1 == s2 {
+                return self.double();
+            }
+            return Projective::identity();
+        }
+
+        let h = u2 - u1;
+        let i = h.double().square();
+        let j = h * i;
+        let r = (s2 - s1).double();
+        let v = u1 * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (s1 * j).double();
+        let z3 = ((self.z + other.z).square() - z1z1 - z2z2) * h;
+        Projective { x: x3, y: y3, z: z3 }
+    }
+
+    pub fn negate(self) -> Projective<C> {
+        Projective {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+
+    // Double-and-add over a little-endian limb representation of the scalar
+    pub fn mul_limbs(self, limbs: &[u64]) -> Projective<C> {
+        let mut result = Projective::identity();
+        for limb in limbs.iter().rev() {
+            for i in (0..64).rev() {
+                result = result.double();
+                if (limb >> i) & 1 == 1 {
+                    result = result.add(self);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn scalar_mul<F: PrimeField>(self, scalar: F) -> Projective<C> {
+        self.mul_limbs(&scalar.to_limbs())
+    }
+
+    pub fn msm<F: PrimeField>(bases: &[Projective<C>], scalars: &[F]) -> Result<Projective<C>> {
+        if bases.len() != scalars.len() {
+            return Err(Error::DimensionMismatch { expected: bases.len(), found: scalars.len() });
+        }
+        Ok(bases.iter()
+            .zip(scalars.iter())
+            .fold(Projective::identity(), |acc, (base, &scalar)| acc + base.scalar_mul(scalar)))
+    }
+}
+
+impl<C: CurveParams> PartialEq for Projective<C> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_identity() || other.is_identity() {
+            return self.is_identity() && other.is_identity();
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        self.x * z2z2 == other.x * z1z1
+            && self.y * z2z2 * other.z == other.y * z1z1 * self.z
+    }
+}
+
+impl<C: CurveParams> Eq for Projective<C> {}
+
+impl<C: CurveParams> From<Affine<C>> for Projective<C> {
+    fn from(point: Affine<C>) -> Self {
+        point.to_projective()
+    }
+}
+
+impl<C: CurveParams> From<Projective<C>> for Affine<C> {
+    fn from(point: Projective<C>) -> Self {
+        point.to_affine()
+    }
+}
+
+impl<C: CurveParams> Add for Projective<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Projective::add(self, rhs)
+    }
+}
+
+impl<C: CurveParams> Sub for Projective<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Projective::add(self, rhs.negate())
+    }
+}
+
+impl<C: CurveParams> Neg for Projective<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl<C: CurveParams, F: PrimeField> Mul<F> for Projective<C> {
+    type Output = Self;
+
+    fn mul(self, scalar: F) -> Self::Output {
+        self.scalar_mul(scalar)
+    }
+}
@@ -0,0 +1,290 @@
+use crate::error::{Error, Result};
+use crate::field::{FftField, Field};
+
+// Multiplicative subgroup {1, w, ..., w^(n-1)} of size n = 2^k, with radix-2 NTTs over it
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct EvaluationDomain<F: FftField> {
+    size: usize,
+    log_size: u32,
+    size_inv: F,
+    group_gen: F,
+    group_gen_inv: F,
+    // Evaluations on g * H avoid the roots of the vanishing polynomial
+    coset_shift: F,
+    coset_shift_inv: F,
+}
+
+impl<F: FftField> EvaluationDomain<F> {
+    // Smallest domain holding num_coeffs points, None if the field has no such subgroup
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        let size = num_coeffs.max(1).next_power_of_two();
+        let log_size = size.trailing_zeros();
+        if log_size > F::TWO_ADICITY {
+            return None;
+        }
+
+        let mut group_gen = F::two_adic_root_of_unity();
+        for _ in log_size..F::TWO_ADICITY {
+            group_gen = group_gen.square();
+        }
+        let coset_shift = F::multiplicative_generator();
+
+        Some(EvaluationDomain {
+            size,
+            log_size,
+            size_inv: F::from_u64(size as u64).inverse()?,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            coset_shift,
+            coset_shift_inv: coset_shift.inverse()?,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    // w^i
+    pub fn element(&self, i: usize) -> F {
+        self.group_gen.pow(&[i as u64])
+    }
+
+    pub fn elements(&self) -> Vec<F> {
+        powers(F::one(), self.group_gen, self.size)
+    }
+
+    // Z(x) = x^n - 1
+    pub fn evaluate_vanishing_polynomial(&self, x: F) -> F {
+        x.pow(&[self.size as u64]) - F::one()
+    }
+
+    // L_j(tau) = w^j (tau^n - 1) / (n (tau - w^j)) for every j, the Lagrange basis of H at tau,
+    // so a polynomial given by its evaluations e_j over H is sum e_j L_j(tau) there
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        let elements = self.elements();
+        let z = self.evaluate_vanishing_polynomial(tau);
+        if z.is_zero() {
+            // tau is some w^j, where L_j is one and every other L_i zero
+            return elements.iter().map(|&x| if x == tau { F::one() } else { F::zero() }).collect();
+        }
+
+        let mut denominators: Vec<F> = elements.iter().map(|&x| tau - x).collect();
+        batch_inverse(&mut denominators);
+        let scale = z * self.size_inv;
+        elements.iter().zip(denominators).map(|(&x, d)| scale * x * d).collect()
+    }
+
+    // Coefficients -> evaluations over H
+    pub fn fft(&self, coeffs: &[F]) -> Result<Vec<F>> {
+        let mut values = self.padded(coeffs)?;
+        self.ntt(&mut values, self.group_gen);
+        Ok(values)
+    }
+
+    // Evaluations over H -> coefficients
+    pub fn ifft(&self, evals: &[F]) -> Result<Vec<F>> {
+        let mut values = self.padded(evals)?;
+        self.ntt(&mut values, self.group_gen_inv);
+        for value in values.iter_mut() {
+            *value = *value * self.size_inv;
+        }
+        Ok(values)
+    }
+
+    // Coefficients -> evaluations over g * H
+    pub fn coset_fft(&self, coeffs: &[F]) -> Result<Vec<F>> {
+        let shifts = powers(F::one(), self.coset_shift, coeffs.len());
+        let shifted: Vec<F> = coeffs.iter().zip(shifts).map(|(&c, s)| c * s).collect();
+        self.fft(&shifted)
+    }
+
+    // Evaluations over g * H -> coefficients
+    pub fn coset_ifft(&self, evals: &[F]) -> Result<Vec<F>> {
+        let mut coeffs = self.ifft(evals)?;
+        let shifts = powers(F::one(), self.coset_shift_inv, coeffs.len());
+        for (coeff, shift) in coeffs.iter_mut().zip(shifts) {
+            *coeff = *coeff * shift;
+        }
+        Ok(coeffs)
+    }
+
+    // Z(g * w^i) = g^n - 1 is the same at every coset point
+    pub fn divide_by_vanishing_poly_on_coset_in_place(&self, evals: &mut [F]) {
+        let z_inv = self.evaluate_vanishing_polynomial(self.coset_shift)
+            .inverse()
+            .expect("Coset shift lies in the domain");
+        for eval in evals.iter_mut() {
+            *eval = *eval * z_inv;
+        }
+    }
+
+    fn padded(&self, values: &[F]) -> Result<Vec<F>> {
+        if values.len() > self.size {
+            return Err(Error::DimensionMismatch { expected: self.size, found: values.len() });
+        }
+        let mut padded = values.to_vec();
+        padded.resize(self.size, F::zero());
+        Ok(padded)
+    }
+
+    // Iterative Cooley-Tukey, bit-reversed input order, root of order n
+    fn ntt(&self, values: &mut [F], root: F) {
+        let n = self.size;
+        for i in 0..n {
+            let j = bit_reverse(i, self.log_size);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        let mut half = 1;
+        while half < n {
+            // Principal 2 * half-th root of unity
+            let step = root.pow(&[(n / (2 * half)) as u64]);
+            let twiddles = powers(F::one(), step, half);
+            for chunk in values.chunks_mut(2 * half) {
+                let (lo, hi) = chunk.split_at_mut(half);
+                for ((a, b), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(twiddles.iter()) {
+                    let t = *b * w;
+                    *b = *a - t;
+                    *a = *a + t;
+                }
+            }
+            half *= 2;
+        }
+    }
+}
+
+// start, start * step, start * step^2, ...
+fn powers<F: Field>(start: F, step: F, count: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(count);
+    let mut current = start;
+    for _ in 0..count {
+        result.push(current);
+        current = current * step;
+    }
+    result
+}
+
+// Inverts every (non-zero) value with a single field inversion, Montgomery's trick
+fn batch_inverse<F: Field>(values: &mut [F]) {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut product = F::one();
+    for &value in values.iter() {
+        prefix.push(product);
+        product = product * value;
+    }
+    let mut inv = product.inverse().expect("Values are non-zero");
+    for (value, before) in values.iter_mut().zip(prefix).rev() {
+        let value_inv = inv * before;
+        inv = inv * *value;
+        *value = value_inv;
+    }
+}
+
+fn bit_reverse(i: usize, bits: u32) -> usize {
+    if bits == 0 {
+        return 0;
+    }
+    i.reverse_bits() >> (usize::BITS - bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::Fr;
+    use crate::field::Goldilocks;
+    use crate::polynomial::Polynomial;
+
+    fn random_coeffs<F: Field>(len: usize) -> Vec<F> {
+        (0..len).map(|_| F::random()).collect()
+    }
+
+    fn fft_matches_evaluation<F: FftField>() {
+        let domain = EvaluationDomain::<F>::new(8).unwrap();
+        let coeffs = random_coeffs::<F>(8);
+        let poly = Polynomial::new(coeffs.clone());
+        let evals = domain.fft(&coeffs).unwrap();
+        for (x, eval) in domain.elements().into_iter().zip(&evals) {
+            assert_eq!(poly.evaluate(x), *eval);
+        }
+        assert_eq!(domain.ifft(&evals).unwrap(), coeffs);
+    }
+
+    fn coset_round_trip<F: FftField>() {
+        let domain = EvaluationDomain::<F>::new(16).unwrap();
+        let coeffs = random_coeffs::<F>(16);
+        let poly = Polynomial::new(coeffs.clone());
+        let evals = domain.coset_fft(&coeffs).unwrap();
+        let g = F::multiplicative_generator();
+        for (x, eval) in domain.elements().into_iter().zip(&evals) {
+            assert_eq!(poly.evaluate(g * x), *eval);
+        }
+        assert_eq!(domain.coset_ifft(&evals).unwrap(), coeffs);
+    }
+
+    #[test]
+    fn bn254_fft_and_ifft() {
+        fft_matches_evaluation::<Fr>();
+    }
+
+    #[test]
+    fn goldilocks_fft_and_ifft() {
+        fft_matches_evaluation::<Goldilocks>();
+    }
+
+    #[test]
+    fn bn254_coset_fft_and_ifft() {
+        coset_round_trip::<Fr>();
+    }
+
+    #[test]
+    fn goldilocks_coset_fft_and_ifft() {
+        coset_round_trip::<Goldilocks>();
+    }
+
+    #[test]
+    fn short_inputs_are_zero_padded() {
+        let domain = EvaluationDomain::<Fr>::new(5).unwrap();
+        assert_eq!(domain.size(), 8);
+        let coeffs = random_coeffs::<Fr>(3);
+        let mut padded = coeffs.clone();
+        padded.resize(8, Fr::zero());
+        assert_eq!(domain.ifft(&domain.fft(&coeffs).unwrap()).unwrap(), padded);
+    }
+
+    #[test]
+    fn long_inputs_are_rejected() {
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let coeffs = random_coeffs::<Fr>(9);
+        assert!(matches!(domain.fft(&coeffs), Err(Error::DimensionMismatch { expected: 8, found: 9 })));
+        assert!(matches!(domain.ifft(&coeffs), Err(Error::DimensionMismatch { expected: 8, found: 9 })));
+        assert!(matches!(domain.coset_fft(&coeffs), Err(Error::DimensionMismatch { expected: 8, found: 9 })));
+        assert!(matches!(domain.coset_ifft(&coeffs), Err(Error::DimensionMismatch { expected: 8, found: 9 })));
+    }
+
+    #[test]
+    fn vanishing_polynomial_is_zero_on_the_domain() {
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        assert!(domain.elements().into_iter().all(|x| domain.evaluate_vanishing_polynomial(x).is_zero()));
+        assert!(!domain.evaluate_vanishing_polynomial(Fr::multiplicative_generator()).is_zero());
+    }
+
+    #[test]
+    fn lagrange_coefficients_interpolate() {
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let coeffs = random_coeffs::<Fr>(8);
+        let evals = domain.fft(&coeffs).unwrap();
+        let tau = Fr::random();
+        let lagrange = domain.evaluate_all_lagrange_coefficients(tau);
+        let interpolated = evals.iter().zip(&lagrange).fold(Fr::zero(), |acc, (&e, &l)| acc + e * l);
+        assert_eq!(interpolated, Polynomial::new(coeffs).evaluate(tau));
+
+        let on_domain = domain.evaluate_all_lagrange_coefficients(domain.element(3));
+        assert!(on_domain.iter().enumerate().all(|(j, &l)| l == if j == 3 { Fr::one() } else { Fr::zero() }));
+    }
+}
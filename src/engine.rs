@@ -0,0 +1,33 @@
+use std::fmt::Debug;
+use std::ops::Mul;
+
+use crate::curve::{Affine, CurveParams};
+use crate::error::Result;
+use crate::field::FftField;
+use crate::serialize::Encode;
+
+// A pairing-friendly curve: the backend the trusted setup, prover and verifier run over
+pub trait Engine: Copy + Clone + Debug + PartialEq + Eq {
+    // Written into key and proof files so they are not mixed up across curves
+    const NAME: &'static str;
+
+    // Scalar field, the prime order of G1 and G2
+    type Fr: FftField + Encode;
+
+    type G1: CurveParams<Base: Encode>;
+
+    type G2: CurveParams<Base: Encode>;
+
+    type Gt: Copy + Clone + Debug + PartialEq + Eq + Mul<Output = Self::Gt>;
+
+    // Miller loop line coefficients for a fixed G2 point
+    type G2Prepared: Clone + Debug + PartialEq + Eq;
+
+    // Both fail only for an on-curve G2 point outside the order-r subgroup
+    fn pairing(p: &Affine<Self::G1>, q: &Affine<Self::G2>) -> Result<Self::Gt>;
+
+    fn prepare_g2(q: &Affine<Self::G2>) -> Result<Self::G2Prepared>;
+
+    // prod e(P_i, Q_i) with one final exponentiation
+    fn multi_pairing(pairs: &[(&Affine<Self::G1>, &Self::G2Prepared)]) -> Self::Gt;
+}
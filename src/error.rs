@@ -0,0 +1,68 @@
+use std::fmt;
+
+use crate::r1cs::ConstraintName;
+
+// Everything that can go wrong between building a circuit and verifying its proof
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    // Every constraint the witness violates
+    UnsatisfiedConstraints(Vec<UnsatisfiedConstraint>),
+    DimensionMismatch { expected: usize, found: usize },
+    NonInvertible,
+    // The field has no power-of-two subgroup holding this many constraints
+    DomainTooLarge(usize),
+    MalformedProof,
+    PairingCheckFailed,
+    // A circuit asked for a value it was not given, e.g. while proving
+    AssignmentMissing,
+    // Malformed circuit, key, proof or witness text
+    Parse(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// A violated constraint with A·w, B·w and C·w as evaluated, in decimal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint {
+    pub index: usize,
+    pub name: Option<ConstraintName>,
+    pub left: String,
+    pub right: String,
+    pub output: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnsatisfiedConstraints(unsatisfied) => {
+                write!(f, "{} R1CS constraint(s) not satisfied", unsatisfied.len())?;
+                for constraint in unsatisfied {
+                    write!(f, "\n  {}", constraint)?;
+                }
+                Ok(())
+            }
+            Error::DimensionMismatch { expected, found } => {
+                write!(f, "Dimension mismatch: expected {}, found {}", expected, found)
+            }
+            Error::NonInvertible => write!(f, "Element is not invertible"),
+            Error::DomainTooLarge(size) => write!(f, "No evaluation domain of size {} in the field", size),
+            Error::MalformedProof => write!(f, "Malformed proof"),
+            Error::PairingCheckFailed => write!(f, "Pairing check failed"),
+            Error::AssignmentMissing => write!(f, "Circuit variable has no assigned value"),
+            Error::Parse(message) => write!(f, "Parse error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// constraint 3 (hash/round 2/mix): A·w = .., B·w = .., C·w = ..
+impl fmt::Display for UnsatisfiedConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "constraint {}", self.index)?;
+        if let Some(name) = &self.name {
+            write!(f, " ({})", name)?;
+        }
+        write!(f, ": A·w = {}, B·w = {}, C·w = {}", self.left, self.right, self.output)
+    }
+}
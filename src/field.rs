@@ -1,93 +1,213 @@
-use std::ops::{Add, Mul, Sub};
-use rand::{RngCore, Rng};
+use std::fmt::{self, Debug, Display};
+use std::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
 
-const MODULUS256: [u64; 4] = [
-    0xffffffffffffffff, // 0xFFFFFFFFFFFFFFFF
-    0xffffffffffffffff, // 0xFFFFFFFFFFFFFFFF
-    0xffffffffffffffff, // 0xFFFFFFFFFFFFFFFF
-    0xffffffffffffffff, // 0xFFFFFFFFFFFFFFFF
-];
+use crate::error::Result;
+use crate::fp::{Fp, FpConfig};
 
-pub struct FieldElement256 {
-    value: [u64; 4],
+// Arithmetic shared by every field the curves, R1CS, QAP and trusted setup are built over
+pub trait Field:
+    Copy + Clone + Debug + PartialEq + Eq
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn is_zero(&self) -> bool;
+
+    fn from_u64(value: u64) -> Self;
+
+    fn from_i64(value: i64) -> Self {
+        let magnitude = Self::from_u64(value.unsigned_abs());
+        if value < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    fn random() -> Self {
+        Self::random_with(&mut rand::thread_rng())
+    }
+
+    // Uniform element drawn from the given source of randomness
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self;
+
+    // Little-endian limbs of the prime p
+    fn characteristic() -> Vec<u64>;
+
+    fn square(&self) -> Self {
+        *self * *self
+    }
+
+    fn double(&self) -> Self {
+        *self + *self
+    }
+
+    // None for zero
+    fn inverse(&self) -> Option<Self>;
+
+    // Square-and-multiply over a little-endian limb exponent
+    fn pow(&self, exp: &[u64]) -> Self {
+        let mut result = Self::one();
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                result = result.square();
+                if (limb >> i) & 1 == 1 {
+                    result = result * *self;
+                }
+            }
+        }
+        result
+    }
+}
+
+// Fields of prime order, whose elements double as curve scalars and print as decimal integers
+pub trait PrimeField: Field + Display {
+    // Canonical little-endian limbs
+    fn to_limbs(&self) -> Vec<u64>;
+}
+
+// Prime fields with a large power-of-two subgroup in F*, so radix-2 NTTs of size up to 2^TWO_ADICITY exist
+pub trait FftField: PrimeField {
+    // Largest s with 2^s | p - 1
+    const TWO_ADICITY: u32;
+
+    fn multiplicative_generator() -> Self;
+
+    // g^((p - 1) / 2^s), a primitive 2^s-th root of unity
+    fn two_adic_root_of_unity() -> Self {
+        let mut exp = Self::characteristic();
+        exp[0] -= 1;
+        for _ in 0..Self::TWO_ADICITY {
+            let mut carry = 0;
+            for limb in exp.iter_mut().rev() {
+                let next = *limb & 1;
+                *limb = (*limb >> 1) | (carry << 63);
+                carry = next;
+            }
+        }
+        Self::multiplicative_generator().pow(&exp)
+    }
+}
+
+// secp256k1 base field, p = 2^256 - 2^32 - 977
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Secp256k1Config;
+
+impl FpConfig<4> for Secp256k1Config {
+    const MODULUS: [u64; 4] = [
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ];
+    const GENERATOR: u64 = 3;
+}
+
+// 256-bit prime field element, backed by the Montgomery arithmetic in fp.rs
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct FieldElement256<P: FpConfig<4> = Secp256k1Config> {
+    value: Fp<P, 4>,
 }
 
 pub const MODULUS64: u64 = 0xFFFFFFFFFFFFFFC5;
 
+// p = 2^64 - 59
 #[derive(Clone, Debug, PartialEq, Eq, Copy)]
-pub struct FieldElement64 {
-    value: u64,
+pub struct Modulus64Config;
+
+impl FpConfig<1> for Modulus64Config {
+    const MODULUS: [u64; 1] = [MODULUS64];
+    const GENERATOR: u64 = 2;
 }
 
-impl FieldElement64 {
-    pub fn new(mut value: u64) -> Self {
-        value %= MODULUS64;
-        FieldElement64 { value }
-    }
+// Goldilocks, p = 2^64 - 2^32 + 1, with 2-adicity 32
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct GoldilocksConfig;
 
-    pub fn add(self, addend: FieldElement64) -> FieldElement64 {
-        let sum = self.value.wrapping_add(addend.value);
-        FieldElement64::new(sum % MODULUS64)
-    }
+impl FpConfig<1> for GoldilocksConfig {
+    const MODULUS: [u64; 1] = [0xffffffff00000001];
+    const GENERATOR: u64 = 7;
+}
 
-    pub fn subtract(self, subtrahend: FieldElement64) -> FieldElement64 {
-        let mut result = self.value.wrapping_sub(subtrahend.value);
-        if result >= MODULUS64 {
-            result = result.wrapping_add(MODULUS64);
-        }
-        FieldElement64 { value: result }
+// Kept in Montgomery form internally; new() and value() convert at the boundary
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct FieldElement64<P: FpConfig<1> = Modulus64Config> {
+    value: Fp<P, 1>,
+}
+
+// NTT-friendly alternative to the default 64-bit field
+pub type Goldilocks = FieldElement64<GoldilocksConfig>;
+
+impl<P: FpConfig<1>> FieldElement64<P> {
+    pub const MODULUS: u64 = P::MODULUS[0];
+
+    pub fn new(value: u64) -> Self {
+        FieldElement64 { value: Fp::new(value) }
     }
 
-    pub fn multiply(self, multiplier: FieldElement64) -> FieldElement64 {
-        let product = (self.value as u128).wrapping_mul(multiplier.value as u128);
-        FieldElement64::new((product % MODULUS64 as u128) as u64)
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, addend: Self) -> Self {
+        FieldElement64 { value: self.value + addend.value }
     }
 
-    pub fn inverse(self) -> FieldElement64 {
-        let mut t = 0u64;
-        let mut new_t = 1u64;
-        let mut r = MODULUS64;
-        let mut new_r = self.value;
+    pub fn subtract(self, subtrahend: Self) -> Self {
+        FieldElement64 { value: self.value - subtrahend.value }
+    }
 
-        while new_r != 0 {
-            let quotient = r / new_r;
+    pub fn multiply(self, multiplier: Self) -> Self {
+        FieldElement64 { value: self.value * multiplier.value }
+    }
 
-            let temp_t = t;
-            t = new_t;
-            new_t = temp_t.wrapping_sub(quotient.wrapping_mul(new_t));
+    pub fn square(self) -> Self {
+        self.multiply(self)
+    }
 
-            let temp_r = r;
-            r = new_r;
-            new_r = temp_r.wrapping_sub(quotient.wrapping_mul(new_r));
-        }
+    pub fn double(self) -> Self {
+        self.add(self)
+    }
 
-        assert_eq!(r, 1, "Element is not invertible");
+    pub fn negate(self) -> Self {
+        FieldElement64::new(0).subtract(self)
+    }
 
-        FieldElement64::new(t as u64)
+    pub fn inverse(self) -> Result<Self> {
+        Ok(FieldElement64 { value: Fp::inverse(self.value)? })
     }
 
+    // Uniform, by Fp's rejection sampling
     pub fn random() -> Self {
-        let mut rng = rand::thread_rng();
-        let value = rng.gen::<u64>() % MODULUS64;
-        FieldElement64 { value }
+        FieldElement64::random_with(&mut rand::thread_rng())
+    }
+
+    // Uniform, by Fp's rejection sampling
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        FieldElement64 { value: Fp::random_with(rng) }
     }
 
+    // Canonical integer in [0, p)
     pub fn value(&self) -> u64 {
-        self.value
+        self.value.value()[0]
     }
 
-    pub fn convert1d(matrix: &mut Vec<u64>) -> Vec<FieldElement64> {
+    pub fn is_zero(&self) -> bool {
+        Field::is_zero(&self.value)
+    }
+
+    pub fn convert1d(matrix: &mut [u64]) -> Vec<Self> {
         matrix.iter().map(|&x| FieldElement64::new(x)).collect()
     }
 
-    pub fn convert2d(matrix: &mut Vec<Vec<u64>>) -> Vec<Vec<FieldElement64>> {
+    pub fn convert2d(matrix: &mut [Vec<u64>]) -> Vec<Vec<Self>> {
         matrix.iter()
         .map(|row| row.iter().map(|&x| FieldElement64::new(x)).collect())
         .collect()
     }
 }
 
-impl Add for FieldElement64 {
+impl<P: FpConfig<1>> Add for FieldElement64<P> {
     type Output = Self;
 
     fn add(self, addend: Self) -> Self::Output {
@@ -95,7 +215,7 @@ impl Add for FieldElement64 {
     }
 }
 
-impl Mul for FieldElement64 {
+impl<P: FpConfig<1>> Mul for FieldElement64<P> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
@@ -103,7 +223,7 @@ impl Mul for FieldElement64 {
     }
 }
 
-impl Sub for FieldElement64 {
+impl<P: FpConfig<1>> Sub for FieldElement64<P> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -111,114 +231,183 @@ impl Sub for FieldElement64 {
     }
 }
 
-impl FieldElement256 {
-    pub fn new(mut value: [u64; 4]) -> Self {
-        FieldElement256::reduce(&mut value);
-        FieldElement256 {value}
+impl<P: FpConfig<1>> Neg for FieldElement64<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl<P: FpConfig<1>> Field for FieldElement64<P> {
+    fn zero() -> Self {
+        FieldElement64::new(0)
     }
 
-    pub fn reduce(value: &mut [u64; 4]) {
-        if greater_equal(value, &MODULUS256) {
-            wrapper(value, &MODULUS256);
-        }
+    fn one() -> Self {
+        FieldElement64::new(1)
+    }
 
-        fn greater_equal(a: &[u64; 4], b: &[u64; 4]) -> bool {
-            for (x,y) in a.iter().rev().zip(b.iter().rev()) {
-                if x != y {
-                    return x > y;
-                }
-            }
-            true
-        }
+    fn is_zero(&self) -> bool {
+        FieldElement64::is_zero(self)
+    }
 
-        fn wrapper(a: &mut [u64; 4], b: &[u64; 4]) {
-            let mut borrow = 0u64;
-            for i in 0..4 {
-                let (res, overflow) = a[i].overflowing_sub(b[i] + borrow);
-                a[i] = res;
-                borrow = if overflow { 1 } else { 0 };
-            }
-        }
+    fn from_u64(value: u64) -> Self {
+        FieldElement64::new(value)
     }
 
-    pub fn add(self, addend: FieldElement256) -> FieldElement256 {
-        let mut result = [0u64; 4];
-        let mut carry = 0;
-    
-        for i in 0..4 {
-            let (sum, overflow1) = self.value[i].overflowing_add(addend.value[i]);
-            let (sum_with_carry, overflow2) = sum.overflowing_add(carry);
-    
-            result[i] = sum_with_carry;
-            carry = (overflow1 as u64) + (overflow2 as u64);
-        }
-    
-        FieldElement256::new(result)
-    }
-    
-
-    pub fn subtract(self, subtrahend: FieldElement256) -> FieldElement256 {
-        let mut result = [0u64; 4];
-        let mut borrow = 0;
-    
-        for i in 0..4 {
-            let (diff, overflow) = self.value[i].overflowing_sub(subtrahend.value[i] + borrow);
-            result[i] = diff;
-            borrow = overflow as u64;
-        }
-    
-        if borrow != 0 {
-            let mut carry = 0;
-            for i in 0..4 {
-                let (sum, overflow) = result[i].overflowing_add(MODULUS256[i] + carry);
-                result[i] = sum;
-                carry = overflow as u64;
-            }
-        }
-    
-        FieldElement256::new(result)
-    }
-    
-    pub fn multiply(self, multiplier: FieldElement256) -> FieldElement256 {
-        let mut result = [0u64; 8]; 
-        for i in 0..4 {
-            let mut carry = 0u64;
-            for j in 0..4 {
-                let (low, high) = self.value[i].overflowing_mul(multiplier.value[j]);
-                
-                let (res_low, c1) = result[i + j].overflowing_add(low);
-                result[i + j] = res_low;
-                carry = c1 as u64;
-    
-                let (res_high, c2) = result[i + j + 1].overflowing_add(carry);
-                result[i + j + 1] = res_high;
-                carry = c2 as u64;
-            }
-        }
-    
-        let mut reduced = [0u64; 4];
-        let mut carry = 0u64;
-        for i in 0..4 {
-            let (sum, overflow) = result[i].overflowing_add(carry);
-            reduced[i] = sum;
-            carry = if overflow { 1 } else { 0 };
-        }
-    
-        FieldElement256::new(reduced)
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        FieldElement64::random_with(rng)
+    }
+
+    fn characteristic() -> Vec<u64> {
+        vec![Self::MODULUS]
     }
-    
-    fn is_zero(value: &[u64; 4]) -> bool {
-        value.iter().all(|&x| x == 0)
+
+    fn square(&self) -> Self {
+        self.multiply(*self)
+    }
+
+    fn double(&self) -> Self {
+        FieldElement64::add(*self, *self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        FieldElement64::inverse(*self).ok()
+    }
+}
+
+impl<P: FpConfig<1>> PrimeField for FieldElement64<P> {
+    fn to_limbs(&self) -> Vec<u64> {
+        vec![self.value()]
+    }
+}
+
+impl<P: FpConfig<1>> Display for FieldElement64<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
     }
-    
+}
+
+impl<P: FpConfig<1>> FftField for FieldElement64<P> {
+    const TWO_ADICITY: u32 = Fp::<P, 1>::TWO_ADICITY;
+
+    fn multiplicative_generator() -> Self {
+        FieldElement64::new(P::GENERATOR)
+    }
+}
+
+impl<P: FpConfig<4>> FieldElement256<P> {
+    // Little-endian limbs, reduced mod p
+    pub fn new(value: [u64; 4]) -> Self {
+        FieldElement256 { value: Fp::from_limbs(value) }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, addend: Self) -> Self {
+        FieldElement256 { value: self.value + addend.value }
+    }
+
+    pub fn subtract(self, subtrahend: Self) -> Self {
+        FieldElement256 { value: self.value - subtrahend.value }
+    }
+
+    pub fn multiply(self, multiplier: Self) -> Self {
+        FieldElement256 { value: self.value * multiplier.value }
+    }
+
+    pub fn inverse(self) -> Result<Self> {
+        Ok(FieldElement256 { value: Fp::inverse(self.value)? })
+    }
+
     pub fn random() -> Self {
-        let mut rng = rand::thread_rng();
-        let mut value = [0u64; 4];
-        for limb in value.iter_mut() {
-            *limb = rng.next_u64();
-        }
-    
-        FieldElement256::new(value)
+        FieldElement256 { value: Fp::random() }
+    }
+
+    // Canonical little-endian limbs
+    pub fn value(&self) -> [u64; 4] {
+        self.value.value()
+    }
+}
+
+impl<P: FpConfig<4>> Field for FieldElement256<P> {
+    fn zero() -> Self {
+        FieldElement256 { value: Fp::zero() }
+    }
+
+    fn one() -> Self {
+        FieldElement256 { value: Fp::one() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        FieldElement256 { value: Fp::new(value) }
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        FieldElement256 { value: Fp::random_with(rng) }
     }
-    
-}
\ No newline at end of file
+
+    fn characteristic() -> Vec<u64> {
+        P::MODULUS.to_vec()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Field::inverse(&self.value).map(|value| FieldElement256 { value })
+    }
+}
+
+impl<P: FpConfig<4>> PrimeField for FieldElement256<P> {
+    fn to_limbs(&self) -> Vec<u64> {
+        self.value().to_vec()
+    }
+}
+
+impl<P: FpConfig<4>> Display for FieldElement256<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<P: FpConfig<4>> FftField for FieldElement256<P> {
+    const TWO_ADICITY: u32 = Fp::<P, 4>::TWO_ADICITY;
+
+    fn multiplicative_generator() -> Self {
+        FieldElement256 { value: Fp::multiplicative_generator() }
+    }
+}
+
+impl<P: FpConfig<4>> Add for FieldElement256<P> {
+    type Output = Self;
+
+    fn add(self, addend: Self) -> Self::Output {
+        FieldElement256::add(self, addend)
+    }
+}
+
+impl<P: FpConfig<4>> Sub for FieldElement256<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.subtract(rhs)
+    }
+}
+
+impl<P: FpConfig<4>> Mul for FieldElement256<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.multiply(rhs)
+    }
+}
+
+impl<P: FpConfig<4>> Neg for FieldElement256<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        FieldElement256 { value: -self.value }
+    }
+}
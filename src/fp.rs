@@ -0,0 +1,437 @@
+use std::fmt::{self, Debug, Display};
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+
+use crate::error::{Error, Result};
+use crate::field::{FftField, Field, PrimeField};
+
+// Parameters of a prime field with an N-limb modulus; the Montgomery constants are derived from them
+pub trait FpConfig<const N: usize>: 'static + Copy + Clone + Debug + PartialEq + Eq {
+    // Little-endian limbs of the odd prime p
+    const MODULUS: [u64; N];
+
+    // Smallest generator of the multiplicative group
+    const GENERATOR: u64;
+}
+
+// Prime field element in Montgomery form, x is stored as x * 2^(64N) mod p
+#[derive(Clone, PartialEq, Eq, Copy)]
+pub struct Fp<P: FpConfig<N>, const N: usize> {
+    value: [u64; N],
+    config: PhantomData<P>,
+}
+
+impl<P: FpConfig<N>, const N: usize> Fp<P, N> {
+    // 2^(64N) mod p
+    pub const R: [u64; N] = pow2_mod(&P::MODULUS, 64 * N);
+
+    // 2^(128N) mod p
+    pub const R2: [u64; N] = pow2_mod(&P::MODULUS, 128 * N);
+
+    // -p^{-1} mod 2^64
+    pub const INV: u64 = neg_inverse_mod_2_64(P::MODULUS[0]);
+
+    // Largest s with 2^s | p - 1
+    pub const TWO_ADICITY: u32 = two_adicity(&P::MODULUS);
+
+    pub const MODULUS_BITS: u32 = num_bits(&P::MODULUS);
+
+    const fn from_montgomery(value: [u64; N]) -> Self {
+        Fp { value, config: PhantomData }
+    }
+
+    pub fn new(value: u64) -> Self {
+        let mut limbs = [0u64; N];
+        limbs[0] = value;
+        Fp::from_limbs(limbs)
+    }
+
+    // Little-endian limbs of an integer, reduced mod p
+    pub fn from_limbs(limbs: [u64; N]) -> Self {
+        Fp::from_montgomery(mont_mul::<P, N>(&limbs, &Self::R2))
+    }
+
+    // Canonical little-endian limbs
+    pub fn value(&self) -> [u64; N] {
+        let mut one = [0u64; N];
+        one[0] = 1;
+        mont_mul::<P, N>(&self.value, &one)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, addend: Self) -> Self {
+        let (sum, carry) = add_limbs(&self.value, &addend.value);
+        Fp::from_montgomery(reduce_once::<P, N>(sum, carry))
+    }
+
+    pub fn subtract(self, subtrahend: Self) -> Self {
+        let (diff, borrow) = sub_limbs(&self.value, &subtrahend.value);
+        if borrow {
+            return Fp::from_montgomery(add_limbs(&diff, &P::MODULUS).0);
+        }
+        Fp::from_montgomery(diff)
+    }
+
+    pub fn multiply(self, multiplier: Self) -> Self {
+        Fp::from_montgomery(mont_mul::<P, N>(&self.value, &multiplier.value))
+    }
+
+    // Fermat inversion, a^(p - 2)
+    pub fn inverse(self) -> Result<Self> {
+        if Field::is_zero(&self) {
+            return Err(Error::NonInvertible);
+        }
+        let mut exp = P::MODULUS;
+        exp[0] -= 2;
+        Ok(self.pow(&exp))
+    }
+
+
+    pub fn multiplicative_generator() -> Self {
+        Fp::new(P::GENERATOR)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
+    fn zero() -> Self {
+        Fp::from_montgomery([0u64; N])
+    }
+
+    fn one() -> Self {
+        Fp::from_montgomery(Self::R)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == [0u64; N]
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Fp::new(value)
+    }
+
+    // Uniform by rejection sampling below p
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let top_bits = Self::MODULUS_BITS - 64 * (N as u32 - 1);
+        let mask = if top_bits == 64 { u64::MAX } else { (1u64 << top_bits) - 1 };
+        loop {
+            let mut limbs = [0u64; N];
+            for limb in limbs.iter_mut() {
+                *limb = rng.next_u64();
+            }
+            limbs[N - 1] &= mask;
+            if sub_limbs(&limbs, &P::MODULUS).1 {
+                return Fp::from_limbs(limbs);
+            }
+        }
+    }
+
+    fn characteristic() -> Vec<u64> {
+        P::MODULUS.to_vec()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Fp::inverse(*self).ok()
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> PrimeField for Fp<P, N> {
+    fn to_limbs(&self) -> Vec<u64> {
+        self.value().to_vec()
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> FftField for Fp<P, N> {
+    const TWO_ADICITY: u32 = two_adicity(&P::MODULUS);
+
+    fn multiplicative_generator() -> Self {
+        Fp::new(P::GENERATOR)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Debug for Fp<P, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fp(0x")?;
+        for limb in self.value().iter().rev() {
+            write!(f, "{:016x}", limb)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// Canonical value in decimal, peeled off 19 digits at a time
+impl<P: FpConfig<N>, const N: usize> Display for Fp<P, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut limbs = self.value();
+        let mut chunks = vec![];
+        loop {
+            let mut remainder = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let current = (remainder << 64) | *limb as u128;
+                *limb = (current / CHUNK as u128) as u64;
+                remainder = current % CHUNK as u128;
+            }
+            chunks.push(remainder as u64);
+            if limbs.iter().all(|&limb| limb == 0) {
+                break;
+            }
+        }
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap_or(&0))?;
+        for chunk in chunks {
+            write!(f, "{:019}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Add for Fp<P, N> {
+    type Output = Self;
+
+    fn add(self, addend: Self) -> Self::Output {
+        Fp::add(self, addend)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Sub for Fp<P, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.subtract(rhs)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Mul for Fp<P, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.multiply(rhs)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Neg for Fp<P, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::zero().subtract(self)
+    }
+}
+
+fn add_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = [0u64; N];
+    let mut carry = false;
+    for (i, limb) in result.iter_mut().enumerate() {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        *limb = sum;
+        carry = c1 || c2;
+    }
+    (result, carry)
+}
+
+fn sub_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = [0u64; N];
+    let mut borrow = false;
+    for (i, limb) in result.iter_mut().enumerate() {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        *limb = diff;
+        borrow = b1 || b2;
+    }
+    (result, borrow)
+}
+
+// Subtract p once if the value (with an extra carry bit) is not below it
+fn reduce_once<P: FpConfig<N>, const N: usize>(value: [u64; N], carry: bool) -> [u64; N] {
+    let (diff, borrow) = sub_limbs(&value, &P::MODULUS);
+    if carry || !borrow {
+        diff
+    } else {
+        value
+    }
+}
+
+// a + b * c + carry, returned as (low, high)
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let wide = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (wide as u64, (wide >> 64) as u64)
+}
+
+// CIOS Montgomery multiplication: a * b * 2^(-64N) mod p
+fn mont_mul<P: FpConfig<N>, const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let mut t = [0u64; N];
+    let mut t_hi = 0u64;
+    for &b_i in b.iter() {
+        let mut carry = 0u64;
+        for j in 0..N {
+            (t[j], carry) = mac(t[j], a[j], b_i, carry);
+        }
+        let (sum, overflow) = t_hi.overflowing_add(carry);
+        t_hi = sum;
+        let t_top = overflow as u64;
+
+        let m = t[0].wrapping_mul(Fp::<P, N>::INV);
+        let (_, mut carry) = mac(t[0], m, P::MODULUS[0], 0);
+        for j in 1..N {
+            (t[j - 1], carry) = mac(t[j], m, P::MODULUS[j], carry);
+        }
+        let (sum, overflow) = t_hi.overflowing_add(carry);
+        t[N - 1] = sum;
+        t_hi = t_top + overflow as u64;
+    }
+    reduce_once::<P, N>(t, t_hi != 0)
+}
+
+const fn geq<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+// 2^k mod m, by repeated doubling
+const fn pow2_mod<const N: usize>(m: &[u64; N], k: usize) -> [u64; N] {
+    let mut r = [0u64; N];
+    r[0] = 1;
+    let mut step = 0;
+    while step < k {
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < N {
+            let next = r[i] >> 63;
+            r[i] = (r[i] << 1) | carry;
+            carry = next;
+            i += 1;
+        }
+        if carry == 1 || geq(&r, m) {
+            let mut borrow = 0u64;
+            let mut i = 0;
+            while i < N {
+                let (diff, b1) = r[i].overflowing_sub(m[i]);
+                let (diff, b2) = diff.overflowing_sub(borrow);
+                r[i] = diff;
+                borrow = (b1 || b2) as u64;
+                i += 1;
+            }
+        }
+        step += 1;
+    }
+    r
+}
+
+// Newton iteration, m^(2^63 - 1) = m^{-1} mod 2^64 for odd m
+const fn neg_inverse_mod_2_64(m: u64) -> u64 {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 63 {
+        inv = inv.wrapping_mul(inv);
+        inv = inv.wrapping_mul(m);
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+const fn two_adicity<const N: usize>(m: &[u64; N]) -> u32 {
+    // p is odd, so p - 1 only clears the lowest bit
+    let mut s = 0;
+    let mut i = 0;
+    while i < N {
+        let limb = if i == 0 { m[0] - 1 } else { m[i] };
+        if limb != 0 {
+            return s + limb.trailing_zeros();
+        }
+        s += 64;
+        i += 1;
+    }
+    s
+}
+
+const fn num_bits<const N: usize>(m: &[u64; N]) -> u32 {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if m[i] != 0 {
+            return 64 * i as u32 + 64 - m[i].leading_zeros();
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::fq::FqConfig;
+    use crate::bn254::fr::FrConfig;
+    use crate::bls12_381::fq::FqConfig as Bls12_381FqConfig;
+    use crate::field::{GoldilocksConfig, Secp256k1Config};
+
+    // Reference products and inverses computed with Python's big integers
+    fn check<P: FpConfig<N>, const N: usize>(a: [u64; N], b: [u64; N], product: [u64; N], a_inv: [u64; N]) {
+        let a = Fp::<P, N>::from_limbs(a);
+        let b = Fp::<P, N>::from_limbs(b);
+        assert_eq!((a * b).value(), product);
+        assert_eq!(a.inverse().unwrap().value(), a_inv);
+        assert_eq!(a * a.inverse().unwrap(), Fp::one());
+    }
+
+    #[test]
+    fn goldilocks_mul_and_inverse() {
+        check::<GoldilocksConfig, 1>([0x3898d190f9ebdacc], [0x8e81973e0becd7b0], [0x9b5ea41b5f856042], [0x8a283c0b749b1429]);
+    }
+
+    #[test]
+    fn bn254_mul_and_inverse() {
+        check::<FqConfig, 4>(
+            [0x97b750923ceb3ffd, 0x216363698b529b4a, 0xea7b5bf55eb561a4, 0x1e56e4a79a9a80fd],
+            [0x94b2b8fda02f34a6, 0x9b08923d10c67fd9, 0xe8a8529f035efa25, 0x1e07e716d6645fa9],
+            [0x41c7d8f228f6b01b, 0x29bab0ca75366deb, 0x32e54a7c02b82a9d, 0x0e5268d67200c0a3],
+            [0x5a425a796b5a8c4e, 0xcaf96269e75774fc, 0x6ff0f823ce650a02, 0x2b44a2043f16b123],
+        );
+        check::<FrConfig, 4>(
+            [0x8d0038ec42650644, 0x311624273bfd1d33, 0xb7970386fee29476, 0x229f50ed78633074],
+            [0x8cb4a0d7d6225675, 0x65aa9c8279f248b0, 0xdc6bf1e1a399f82a, 0x0ed7cf61268ecc45],
+            [0xd341ba4803a968ef, 0xcbc55722667c4ea3, 0xe1352d00b39307d8, 0x0255aee1d380af92],
+            [0xf208fc3988790877, 0x1f55bb5c11d0da75, 0x527dcba165276906, 0x0d15ab80eb7d10c6],
+        );
+    }
+
+    #[test]
+    fn secp256k1_mul_and_inverse() {
+        check::<Secp256k1Config, 4>(
+            [0xf2a74de452e6b438, 0x6513270e269e0d37, 0x0c5c7fd0a6a3a450, 0xd23f0824128b2f33],
+            [0x1818e811892f902b, 0x9531985d5d9dc9f8, 0xe8e25d940ed90475, 0x36f675cc81e74ef5],
+            [0xf2a82bcdbc448cf7, 0x54458c0666e3dd27, 0x01de9bae5c09657a, 0xdd756326b2cb45f9],
+            [0xe7fb87c65d62b90e, 0xe78143c86e5d1857, 0x3f3bc20e62f9126a, 0xe03b6ae236ffbff8],
+        );
+    }
+
+    #[test]
+    fn bls12_381_mul_and_inverse() {
+        check::<Bls12_381FqConfig, 6>(
+            [0x1600a35a099950d8, 0x6b0d549b6f03675a, 0x3d9c172411e20b8f, 0x8d116ece1738f7d9, 0x0f21ddb66cad4a26, 0x12183259d3ac94af],
+            [0xf28c105d1fb17c23, 0xa170b33839263059, 0x953f48f1a09f76b5, 0x0fd630f1f29d0da9, 0x95e60af593bd04cf, 0x01963c53658cda14],
+            [0xe1e8a0bd3d017c3a, 0x72dc713f87abb464, 0xbabda4bbb1e8285b, 0xf5ea86e2b5ba612e, 0x91f2bae59e0f4205, 0x164ec7c7b28a8b23],
+            [0xd7d7f381bcbf0ccb, 0xebf38833d998a99e, 0x7d1668074557aa0c, 0x65b0f6a343f79ee6, 0x1407c451f8cd2fd3, 0x15826f502950e471],
+        );
+    }
+
+    #[test]
+    fn zero_has_no_inverse() {
+        assert!(Field::inverse(&Fp::<FqConfig, 4>::zero()).is_none());
+    }
+
+    #[test]
+    fn displays_decimal() {
+        assert_eq!(Fp::<GoldilocksConfig, 1>::zero().to_string(), "0");
+        assert_eq!((-Fp::<GoldilocksConfig, 1>::one()).to_string(), "18446744069414584320");
+        assert_eq!(
+            (-Fp::<Bls12_381FqConfig, 6>::one()).to_string(),
+            "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559786",
+        );
+    }
+}
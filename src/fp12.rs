@@ -0,0 +1,107 @@
+use std::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+
+use crate::field::Field;
+use crate::fp2::{Fp2, Fp2Config};
+use crate::fp6::Fp6;
+
+// Fp12 = Fp6[w] / (w^2 - v)
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Fp12<P: Fp2Config> {
+    pub c0: Fp6<P>,
+    pub c1: Fp6<P>,
+}
+
+impl<P: Fp2Config> Fp12<P> {
+    pub fn new(c0: Fp6<P>, c1: Fp6<P>) -> Fp12<P> {
+        Fp12 { c0, c1 }
+    }
+
+    // Inverse for elements of the cyclotomic subgroup
+    pub fn conjugate(&self) -> Fp12<P> {
+        Fp12::new(self.c0, -self.c1)
+    }
+
+    pub fn frobenius_map(&self, power: usize) -> Fp12<P> {
+        let gamma = Fp2::frobenius_coeff(power);
+        let c1 = self.c1.frobenius_map(power);
+        Fp12::new(
+            self.c0.frobenius_map(power),
+            Fp6::new(c1.c0 * gamma, c1.c1 * gamma, c1.c2 * gamma),
+        )
+    }
+}
+
+impl<P: Fp2Config> Field for Fp12<P> {
+    fn zero() -> Self {
+        Fp12::new(Fp6::zero(), Fp6::zero())
+    }
+
+    fn one() -> Self {
+        Fp12::new(Fp6::one(), Fp6::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Fp12::new(Fp6::from_u64(value), Fp6::zero())
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Fp12::new(Fp6::random_with(rng), Fp6::random_with(rng))
+    }
+
+    fn characteristic() -> Vec<u64> {
+        Fp6::<P>::characteristic()
+    }
+
+    fn square(&self) -> Self {
+        let ab = self.c0 * self.c1;
+        let c0 = (self.c0 + self.c1) * (self.c0 + self.c1.mul_by_nonresidue())
+            - ab
+            - ab.mul_by_nonresidue();
+        Fp12::new(c0, ab.double())
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        let norm = self.c0.square() - self.c1.square().mul_by_nonresidue();
+        Field::inverse(&norm).map(|inv| Fp12::new(self.c0 * inv, -(self.c1 * inv)))
+    }
+}
+
+impl<P: Fp2Config> Add for Fp12<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Fp12::new(self.c0 + rhs.c0, self.c1 + rhs.c1)
+    }
+}
+
+impl<P: Fp2Config> Sub for Fp12<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Fp12::new(self.c0 - rhs.c0, self.c1 - rhs.c1)
+    }
+}
+
+impl<P: Fp2Config> Mul for Fp12<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let aa = self.c0 * rhs.c0;
+        let bb = self.c1 * rhs.c1;
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - aa - bb;
+        Fp12::new(aa + bb.mul_by_nonresidue(), c1)
+    }
+}
+
+impl<P: Fp2Config> Neg for Fp12<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Fp12::new(-self.c0, -self.c1)
+    }
+}
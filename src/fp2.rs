@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+
+use crate::field::Field;
+
+// What a curve supplies for its Fp2 = Fp[u] / (u^2 + 1) and the Fp6, Fp12 built on it
+pub trait Fp2Config: 'static + Copy + Clone + Debug + PartialEq + Eq {
+    // Base prime field, with p = 3 mod 4 so u^2 + 1 is irreducible
+    type Fp: Field;
+
+    // Multiply by the sextic non-residue xi
+    fn mul_by_nonresidue(value: Fp2<Self>) -> Fp2<Self>;
+
+    // xi^((p^k - 1) / 6) for k < 12, usually cached from frobenius_coeffs
+    fn frobenius_coeffs() -> &'static [Fp2<Self>; 12];
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Fp2<P: Fp2Config> {
+    pub c0: P::Fp,
+    pub c1: P::Fp,
+}
+
+impl<P: Fp2Config> Fp2<P> {
+    pub fn new(c0: P::Fp, c1: P::Fp) -> Fp2<P> {
+        Fp2 { c0, c1 }
+    }
+
+    pub fn conjugate(&self) -> Fp2<P> {
+        Fp2::new(self.c0, -self.c1)
+    }
+
+    pub fn scale(&self, scalar: P::Fp) -> Fp2<P> {
+        Fp2::new(self.c0 * scalar, self.c1 * scalar)
+    }
+
+    pub fn mul_by_nonresidue(&self) -> Fp2<P> {
+        P::mul_by_nonresidue(*self)
+    }
+
+    pub fn frobenius_map(&self, power: usize) -> Fp2<P> {
+        if power % 2 == 1 {
+            self.conjugate()
+        } else {
+            *self
+        }
+    }
+
+    // xi^((p^power - 1) / 6), the building block of every Frobenius coefficient in the tower
+    pub fn frobenius_coeff(power: usize) -> Fp2<P> {
+        P::frobenius_coeffs()[power % 12]
+    }
+}
+
+impl<P: Fp2Config> Field for Fp2<P> {
+    fn zero() -> Self {
+        Fp2::new(P::Fp::zero(), P::Fp::zero())
+    }
+
+    fn one() -> Self {
+        Fp2::new(P::Fp::one(), P::Fp::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Fp2::new(P::Fp::from_u64(value), P::Fp::zero())
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Fp2::new(P::Fp::random_with(rng), P::Fp::random_with(rng))
+    }
+
+    fn characteristic() -> Vec<u64> {
+        P::Fp::characteristic()
+    }
+
+    fn square(&self) -> Self {
+        let a = self.c0 + self.c1;
+        let b = self.c0 - self.c1;
+        let c = self.c0 * self.c1;
+        Fp2::new(a * b, c.double())
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        let norm = self.c0.square() + self.c1.square();
+        Field::inverse(&norm).map(|inv| Fp2::new(self.c0 * inv, -(self.c1 * inv)))
+    }
+}
+
+impl<P: Fp2Config> Add for Fp2<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Fp2::new(self.c0 + rhs.c0, self.c1 + rhs.c1)
+    }
+}
+
+impl<P: Fp2Config> Sub for Fp2<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Fp2::new(self.c0 - rhs.c0, self.c1 - rhs.c1)
+    }
+}
+
+impl<P: Fp2Config> Mul for Fp2<P> {
+    type Output = Self;
+
+    // Karatsuba
+    fn mul(self, rhs: Self) -> Self::Output {
+        let aa = self.c0 * rhs.c0;
+        let bb = self.c1 * rhs.c1;
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - aa - bb;
+        Fp2::new(aa - bb, c1)
+    }
+}
+
+impl<P: Fp2Config> Neg for Fp2<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Fp2::new(-self.c0, -self.c1)
+    }
+}
+
+// xi^((p^k - 1) / 6) for k < 12, given the limbs of (p - 1) / 6
+pub fn frobenius_coeffs<P: Fp2Config>(modulus_minus_one_div_six: &[u64]) -> [Fp2<P>; 12] {
+    let xi = Fp2::<P>::one().mul_by_nonresidue();
+    let gamma = xi.pow(modulus_minus_one_div_six);
+    let mut coeffs = [Fp2::one(); 12];
+    // (p^k - 1) / 6 = (p - 1) / 6 * (1 + p + ... + p^(k-1))
+    for k in 1..12 {
+        coeffs[k] = coeffs[k - 1] * gamma.frobenius_map(k - 1);
+    }
+    coeffs
+}
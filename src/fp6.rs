@@ -0,0 +1,113 @@
+use std::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+
+use crate::field::Field;
+use crate::fp2::{Fp2, Fp2Config};
+
+// Fp6 = Fp2[v] / (v^3 - xi)
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Fp6<P: Fp2Config> {
+    pub c0: Fp2<P>,
+    pub c1: Fp2<P>,
+    pub c2: Fp2<P>,
+}
+
+impl<P: Fp2Config> Fp6<P> {
+    pub fn new(c0: Fp2<P>, c1: Fp2<P>, c2: Fp2<P>) -> Fp6<P> {
+        Fp6 { c0, c1, c2 }
+    }
+
+    // Multiply by v
+    pub fn mul_by_nonresidue(&self) -> Fp6<P> {
+        Fp6::new(self.c2.mul_by_nonresidue(), self.c0, self.c1)
+    }
+
+    pub fn scale(&self, scalar: P::Fp) -> Fp6<P> {
+        Fp6::new(self.c0.scale(scalar), self.c1.scale(scalar), self.c2.scale(scalar))
+    }
+
+    pub fn frobenius_map(&self, power: usize) -> Fp6<P> {
+        // xi^((p^k - 1) / 3) and xi^(2 (p^k - 1) / 3)
+        let gamma = Fp2::frobenius_coeff(power);
+        let gamma2 = gamma.square();
+        Fp6::new(
+            self.c0.frobenius_map(power),
+            self.c1.frobenius_map(power) * gamma2,
+            self.c2.frobenius_map(power) * gamma2.square(),
+        )
+    }
+}
+
+impl<P: Fp2Config> Field for Fp6<P> {
+    fn zero() -> Self {
+        Fp6::new(Fp2::zero(), Fp2::zero(), Fp2::zero())
+    }
+
+    fn one() -> Self {
+        Fp6::new(Fp2::one(), Fp2::zero(), Fp2::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Fp6::new(Fp2::from_u64(value), Fp2::zero(), Fp2::zero())
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Fp6::new(Fp2::random_with(rng), Fp2::random_with(rng), Fp2::random_with(rng))
+    }
+
+    fn characteristic() -> Vec<u64> {
+        Fp2::<P>::characteristic()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        let t0 = self.c0.square() - (self.c1 * self.c2).mul_by_nonresidue();
+        let t1 = self.c2.square().mul_by_nonresidue() - self.c0 * self.c1;
+        let t2 = self.c1.square() - self.c0 * self.c2;
+        let norm = self.c0 * t0 + (self.c2 * t1 + self.c1 * t2).mul_by_nonresidue();
+        Field::inverse(&norm).map(|inv| Fp6::new(t0 * inv, t1 * inv, t2 * inv))
+    }
+}
+
+impl<P: Fp2Config> Add for Fp6<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Fp6::new(self.c0 + rhs.c0, self.c1 + rhs.c1, self.c2 + rhs.c2)
+    }
+}
+
+impl<P: Fp2Config> Sub for Fp6<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Fp6::new(self.c0 - rhs.c0, self.c1 - rhs.c1, self.c2 - rhs.c2)
+    }
+}
+
+impl<P: Fp2Config> Mul for Fp6<P> {
+    type Output = Self;
+
+    // Karatsuba over the cubic extension
+    fn mul(self, rhs: Self) -> Self::Output {
+        let aa = self.c0 * rhs.c0;
+        let bb = self.c1 * rhs.c1;
+        let cc = self.c2 * rhs.c2;
+
+        let c0 = ((self.c1 + self.c2) * (rhs.c1 + rhs.c2) - bb - cc).mul_by_nonresidue() + aa;
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - aa - bb + cc.mul_by_nonresidue();
+        let c2 = (self.c0 + self.c2) * (rhs.c0 + rhs.c2) - aa + bb - cc;
+        Fp6::new(c0, c1, c2)
+    }
+}
+
+impl<P: Fp2Config> Neg for Fp6<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Fp6::new(-self.c0, -self.c1, -self.c2)
+    }
+}
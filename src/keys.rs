@@ -0,0 +1,201 @@
+use crate::curve::Projective;
+use crate::engine::Engine;
+use crate::error::{Error, Result};
+use crate::qap::QAP;
+
+// Everything the prover needs, including the sparse QAP;
+// the verifying key is embedded so it can be handed out after setup
+pub struct ProvingKey<E: Engine> {
+    qap: QAP<E::Fr>,
+    vk: VerifyingKey<E>,
+    beta_g1: Projective<E::G1>,
+    delta_g1: Projective<E::G1>,
+    tau_g1: Vec<Projective<E::G1>>,
+    tau_g2: Vec<Projective<E::G2>>,
+    l: Vec<Projective<E::G1>>,
+    h: Vec<Projective<E::G1>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey<E: Engine> {
+    alpha_g1: Projective<E::G1>,
+    beta_g2: Projective<E::G2>,
+    gamma_g2: Projective<E::G2>,
+    delta_g2: Projective<E::G2>,
+    ic: Vec<Projective<E::G1>>,
+}
+
+// Verifying key with e(alpha, beta) computed and -gamma, -delta ready for the Miller loop
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedVerifyingKey<E: Engine> {
+    alpha_g1_beta_g2: E::Gt,
+    neg_gamma_g2: E::G2Prepared,
+    neg_delta_g2: E::G2Prepared,
+    ic: Vec<Projective<E::G1>>,
+}
+
+impl<E: Engine> ProvingKey<E> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        qap: QAP<E::Fr>,
+        vk: VerifyingKey<E>,
+        beta_g1: Projective<E::G1>,
+        delta_g1: Projective<E::G1>,
+        tau_g1: Vec<Projective<E::G1>>,
+        tau_g2: Vec<Projective<E::G2>>,
+        l: Vec<Projective<E::G1>>,
+        h: Vec<Projective<E::G1>>,
+    ) -> Result<Self> {
+        // The prover commits to polynomials of degree < n against tau_g1 and tau_g2, to h of
+        // degree < n - 1 against h, and weights l and ic by the private and public witness
+        let n = qap.t_val() as usize;
+        let num_private = qap.num_variables() - qap.num_public() - 1;
+        let checks = [
+            (tau_g1.len() >= n, n, tau_g1.len()),
+            (tau_g2.len() >= n, n, tau_g2.len()),
+            (h.len() + 1 >= n, n - 1, h.len()),
+            (l.len() == num_private, num_private, l.len()),
+            (vk.ic.len() == qap.num_public() + 1, qap.num_public() + 1, vk.ic.len()),
+        ];
+        if let Some(&(_, expected, found)) = checks.iter().find(|(ok, _, _)| !ok) {
+            return Err(Error::DimensionMismatch { expected, found });
+        }
+        Ok(ProvingKey { qap, vk, beta_g1, delta_g1, tau_g1, tau_g2, l, h })
+    }
+
+    pub fn qap(&self) -> &QAP<E::Fr> {
+        &self.qap
+    }
+
+    pub fn vk(&self) -> &VerifyingKey<E> {
+        &self.vk
+    }
+
+    // [alpha]1
+    pub fn alpha_g1(&self) -> Projective<E::G1> {
+        self.vk.alpha_g1
+    }
+
+    // [beta]1
+    pub fn beta_g1(&self) -> Projective<E::G1> {
+        self.beta_g1
+    }
+
+    // [beta]2
+    pub fn beta_g2(&self) -> Projective<E::G2> {
+        self.vk.beta_g2
+    }
+
+    // [delta]1
+    pub fn delta_g1(&self) -> Projective<E::G1> {
+        self.delta_g1
+    }
+
+    // [delta]2
+    pub fn delta_g2(&self) -> Projective<E::G2> {
+        self.vk.delta_g2
+    }
+
+    // [tau^i]1, i < n
+    pub fn tau_g1(&self) -> &[Projective<E::G1>] {
+        &self.tau_g1
+    }
+
+    // [tau^i]2, i < n
+    pub fn tau_g2(&self) -> &[Projective<E::G2>] {
+        &self.tau_g2
+    }
+
+    // [(beta * u_i(tau) + alpha * v_i(tau) + w_i(tau)) / delta]1 for the private inputs
+    pub fn l(&self) -> &[Projective<E::G1>] {
+        &self.l
+    }
+
+    // [t(tau) * tau^i / delta]1, i < n - 1
+    pub fn h(&self) -> &[Projective<E::G1>] {
+        &self.h
+    }
+}
+
+impl<E: Engine> VerifyingKey<E> {
+    pub fn new(
+        alpha_g1: Projective<E::G1>,
+        beta_g2: Projective<E::G2>,
+        gamma_g2: Projective<E::G2>,
+        delta_g2: Projective<E::G2>,
+        ic: Vec<Projective<E::G1>>,
+    ) -> Self {
+        VerifyingKey { alpha_g1, beta_g2, gamma_g2, delta_g2, ic }
+    }
+
+    // [alpha]1
+    pub fn alpha_g1(&self) -> Projective<E::G1> {
+        self.alpha_g1
+    }
+
+    // [beta]2
+    pub fn beta_g2(&self) -> Projective<E::G2> {
+        self.beta_g2
+    }
+
+    // [gamma]2
+    pub fn gamma_g2(&self) -> Projective<E::G2> {
+        self.gamma_g2
+    }
+
+    // [delta]2
+    pub fn delta_g2(&self) -> Projective<E::G2> {
+        self.delta_g2
+    }
+
+    // [(beta * u_i(tau) + alpha * v_i(tau) + w_i(tau)) / gamma]1 for the public inputs
+    pub fn ic(&self) -> &[Projective<E::G1>] {
+        &self.ic
+    }
+
+    // Every point lies in the order-r subgroup, which keeps the Miller loop on G2 free of
+    // the degenerate doublings and additions small-order twist points would hit
+    pub fn is_valid(&self) -> bool {
+        self.alpha_g1.is_in_subgroup::<E::Fr>()
+            && self.beta_g2.is_in_subgroup::<E::Fr>()
+            && self.gamma_g2.is_in_subgroup::<E::Fr>()
+            && self.delta_g2.is_in_subgroup::<E::Fr>()
+            && self.ic.iter().all(|point| point.is_in_subgroup::<E::Fr>())
+    }
+
+    pub fn prepare(&self) -> Result<PreparedVerifyingKey<E>> {
+        Ok(PreparedVerifyingKey {
+            alpha_g1_beta_g2: E::pairing(&self.alpha_g1.to_affine(), &self.beta_g2.to_affine())?,
+            neg_gamma_g2: E::prepare_g2(&self.gamma_g2.negate().to_affine())?,
+            neg_delta_g2: E::prepare_g2(&self.delta_g2.negate().to_affine())?,
+            ic: self.ic.clone(),
+        })
+    }
+}
+
+impl<E: Engine> PreparedVerifyingKey<E> {
+    // e(alpha, beta)
+    pub fn alpha_g1_beta_g2(&self) -> E::Gt {
+        self.alpha_g1_beta_g2
+    }
+
+    pub fn neg_gamma_g2(&self) -> &E::G2Prepared {
+        &self.neg_gamma_g2
+    }
+
+    pub fn neg_delta_g2(&self) -> &E::G2Prepared {
+        &self.neg_delta_g2
+    }
+
+    pub fn ic(&self) -> &[Projective<E::G1>] {
+        &self.ic
+    }
+}
+
+impl<E: Engine> TryFrom<&VerifyingKey<E>> for PreparedVerifyingKey<E> {
+    type Error = Error;
+
+    fn try_from(vk: &VerifyingKey<E>) -> Result<Self> {
+        vk.prepare()
+    }
+}
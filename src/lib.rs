@@ -0,0 +1,180 @@
+// Groth16 over BN254 or BLS12-381: R1CS -> QAP -> trusted setup -> prove -> verify
+// setup and prove are the entry points for a Circuit; trustedsetup and prover take the QAP and witness it synthesizes to
+
+pub mod bls12_381;
+pub mod bn254;
+pub mod circuit;
+pub mod constraint_system;
+pub mod curve;
+pub mod domain;
+pub mod engine;
+pub mod error;
+pub mod field;
+pub mod fp;
+pub mod fp12;
+pub mod fp2;
+pub mod fp6;
+pub mod keys;
+pub mod pairing;
+pub mod polynomial;
+pub mod proof;
+pub mod prover;
+pub mod qap;
+pub mod r1cs;
+pub mod serialize;
+pub mod sparse;
+pub mod trustedsetup;
+pub mod verifier;
+
+pub use circuit::Circuit;
+pub use constraint_system::{ConstraintSystem, ProvingSystem, SetupSystem, Variable};
+pub use engine::Engine;
+pub use error::{Error, Result};
+pub use keys::{PreparedVerifyingKey, ProvingKey, VerifyingKey};
+pub use proof::Proof;
+pub use r1cs::R1CS;
+
+use rand::RngCore;
+
+use qap::QAP;
+
+// Trusted setup for one circuit, synthesized without values; the verifying key is pk.vk()
+pub fn setup<E: Engine, C: Circuit<E::Fr>>(circuit: C) -> Result<ProvingKey<E>> {
+    let mut cs = SetupSystem::new();
+    circuit.synthesize(&mut cs)?;
+    let qap = QAP::from_r1cs(cs.finalize()?)?;
+    trustedsetup::trustedsetup(&qap)
+}
+
+// Proof for a circuit with its values assigned
+pub fn prove<E: Engine, C: Circuit<E::Fr>>(pk: &ProvingKey<E>, circuit: C) -> Result<Proof<E>> {
+    prove_with_rng(pk, circuit, &mut rand::thread_rng())
+}
+
+// As prove, drawing the blinding factors r and s from the given RNG
+pub fn prove_with_rng<E: Engine, C: Circuit<E::Fr>, R: RngCore + ?Sized>(
+    pk: &ProvingKey<E>,
+    circuit: C,
+    rng: &mut R,
+) -> Result<Proof<E>> {
+    let mut cs = ProvingSystem::new();
+    circuit.synthesize(&mut cs)?;
+    let (r1cs, witness) = cs.finalize()?;
+    // Names every failing constraint, which the QAP can only report by index
+    r1cs.verify(&witness)?;
+    prover::create_proof_with_rng(pk, &witness, rng)
+}
+
+// Proof for a bare witness [1, public..., private...], e.g. one read from a file
+pub fn prove_witness<E: Engine>(pk: &ProvingKey<E>, witness: &[E::Fr]) -> Result<Proof<E>> {
+    prove_witness_with_rng(pk, witness, &mut rand::thread_rng())
+}
+
+// As prove_witness, drawing the blinding factors r and s from the given RNG
+pub fn prove_witness_with_rng<E: Engine, R: RngCore + ?Sized>(
+    pk: &ProvingKey<E>,
+    witness: &[E::Fr],
+    rng: &mut R,
+) -> Result<Proof<E>> {
+    prover::create_proof_with_rng(pk, witness, rng)
+}
+
+pub fn verify<E: Engine>(pvk: &PreparedVerifyingKey<E>, public_inputs: &[E::Fr], proof: &Proof<E>) -> Result<()> {
+    verifier::verify_proof(pvk, public_inputs, proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::Bls12_381;
+    use crate::bn254::Bn254;
+    use crate::field::Field;
+    use crate::r1cs::LinearCombination;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    // x^3 + x + 5 = out with out public, plus a public tag no constraint uses
+    struct Cubic<F> {
+        x: Option<F>,
+        out: Option<F>,
+        tag: Option<F>,
+    }
+
+    impl<F: Field> Circuit<F> for Cubic<F> {
+        fn synthesize<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<()> {
+            let out = cs.alloc_input(|| self.out.ok_or(Error::AssignmentMissing))?;
+            cs.alloc_input(|| self.tag.ok_or(Error::AssignmentMissing))?;
+            let x = cs.alloc(|| self.x.ok_or(Error::AssignmentMissing))?;
+            let x_sq = cs.alloc(|| self.x.map(|x| x.square()).ok_or(Error::AssignmentMissing))?;
+            let x_cu = cs.alloc(|| self.x.map(|x| x.square() * x).ok_or(Error::AssignmentMissing))?;
+            cs.enforce(x, x, x_sq);
+            cs.enforce(x_sq, x, x_cu);
+            let sum = LinearCombination::from(x_cu) + x + LinearCombination::zero().term(Variable::one(), F::from_u64(5));
+            cs.enforce(sum, Variable::one(), out);
+            Ok(())
+        }
+    }
+
+    fn cubic<F: Field>(x: u64, out: u64, tag: u64) -> Cubic<F> {
+        Cubic { x: Some(F::from_u64(x)), out: Some(F::from_u64(out)), tag: Some(F::from_u64(tag)) }
+    }
+
+    fn setup_prove_verify<E: Engine>() {
+        let pk = setup::<E, _>(Cubic { x: None, out: None, tag: None }).unwrap();
+        let pvk = pk.vk().prepare().unwrap();
+        let proof = prove(&pk, cubic(3, 35, 7)).unwrap();
+        let inputs = |out, tag| [E::Fr::from_u64(out), E::Fr::from_u64(tag)];
+
+        assert!(verify(&pvk, &inputs(35, 7), &proof).is_ok());
+        assert!(matches!(verify(&pvk, &inputs(36, 7), &proof), Err(Error::PairingCheckFailed)));
+        assert!(matches!(verify(&pvk, &inputs(35, 8), &proof), Err(Error::PairingCheckFailed)));
+        assert!(matches!(verify(&pvk, &inputs(35, 7)[..1], &proof), Err(Error::DimensionMismatch { .. })));
+    }
+
+    fn unsatisfied_witness_is_rejected<E: Engine>() {
+        let pk = setup::<E, _>(Cubic { x: None, out: None, tag: None }).unwrap();
+        match prove(&pk, cubic(3, 36, 7)) {
+            Err(Error::UnsatisfiedConstraints(unsatisfied)) => {
+                assert_eq!(unsatisfied.len(), 1);
+                assert_eq!(unsatisfied[0].index, 2);
+                assert_eq!(unsatisfied[0].output, "36");
+            }
+            other => panic!("expected an unsatisfied constraint, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    // The same seed gives the same blinding, so the same proof
+    fn seeded_proofs_are_reproducible<E: Engine>() {
+        let pk = setup::<E, _>(Cubic { x: None, out: None, tag: None }).unwrap();
+        let mut cs = ProvingSystem::new();
+        cubic::<E::Fr>(3, 35, 7).synthesize(&mut cs).unwrap();
+        let (_, witness) = cs.finalize().unwrap();
+
+        let proof = prove_witness_with_rng(&pk, &witness, &mut StdRng::seed_from_u64(1)).unwrap();
+        assert_eq!(proof, prove_witness_with_rng(&pk, &witness, &mut StdRng::seed_from_u64(1)).unwrap());
+        assert_eq!(proof, prove_with_rng(&pk, cubic(3, 35, 7), &mut StdRng::seed_from_u64(1)).unwrap());
+        assert_ne!(proof, prove_witness_with_rng(&pk, &witness, &mut StdRng::seed_from_u64(2)).unwrap());
+        let inputs = [E::Fr::from_u64(35), E::Fr::from_u64(7)];
+        assert!(verify(&pk.vk().prepare().unwrap(), &inputs, &proof).is_ok());
+    }
+
+    #[test]
+    fn bn254_setup_prove_verify() {
+        setup_prove_verify::<Bn254>();
+    }
+
+    #[test]
+    fn bls12_381_setup_prove_verify() {
+        setup_prove_verify::<Bls12_381>();
+    }
+
+    #[test]
+    fn bn254_unsatisfied_witness() {
+        unsatisfied_witness_is_rejected::<Bn254>();
+    }
+
+    #[test]
+    fn bn254_seeded_proofs() {
+        seeded_proofs_are_reproducible::<Bn254>();
+    }
+}
@@ -1,20 +1,153 @@
-use qap::QAP;
-use r1cs::{Constraint, Element, R1CS};
-use trustedsetup::trustedsetup;
-
-mod r1cs;
-mod qap;
-mod vector;
-mod field;
-mod trustedsetup;
-
-fn main() {
-    let c1 = Constraint::new(Element::new(0, 1), Element::new(1, 1), Element::new(2, 1));
-    let c2 = Constraint::new(Element::new(2, 2), Element::new(3, 1), Element::new(4, 3));
-
-    let r1cs = R1CS::new(vec![c1, c2], vec![1, 2, 3, 4, 5]);
-    let qap = QAP::from_r1cs(r1cs,  vec![1, 2, 3, 4, 5]);
-    let srs_values = trustedsetup(qap.t_val());
-    let prover = qap.evaluate(srs_values.clone());
-    QAP::verify(prover, srs_values);
+use std::fmt;
+use std::fs;
+use std::io;
+use std::process::ExitCode;
+
+use groth16::bls12_381::Bls12_381;
+use groth16::bn254::Bn254;
+use groth16::serialize::{from_text, parse_field_elements, to_text};
+use groth16::{Engine, Proof, ProvingKey, VerifyingKey, R1CS};
+
+const USAGE: &str = "Usage:
+  groth16 setup  --circuit <file> --pk <file> --vk <file> [--curve <curve>]
+  groth16 prove  --pk <file> --witness <file> --proof <file> [--curve <curve>]
+  groth16 verify --vk <file> --proof <file> --public <file> [--curve <curve>]
+
+Curves: bn254 (default), bls12_381";
+
+enum CliError {
+    Usage(String),
+    Io(String, io::Error),
+    Groth16(groth16::Error),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}", message),
+            CliError::Io(path, error) => write!(f, "{}: {}", path, error),
+            CliError::Groth16(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<groth16::Error> for CliError {
+    fn from(error: groth16::Error) -> Self {
+        CliError::Groth16(error)
+    }
+}
+
+// --name value pairs following the subcommand
+struct Options {
+    values: Vec<(String, String)>,
+}
+
+impl Options {
+    fn parse(args: &[String], allowed: &[&str]) -> Result<Self, CliError> {
+        let mut values: Vec<(String, String)> = vec![];
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let name = arg.strip_prefix("--")
+                .filter(|name| allowed.contains(name))
+                .ok_or_else(|| CliError::Usage(format!("unexpected argument {}", arg)))?;
+            if values.iter().any(|(seen, _)| seen == name) {
+                return Err(CliError::Usage(format!("{} given twice", arg)));
+            }
+            let value = args.next().ok_or_else(|| CliError::Usage(format!("{} needs a value", arg)))?;
+            values.push((name.to_string(), value.clone()));
+        }
+        Ok(Options { values })
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.values.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    fn required(&self, name: &str) -> Result<&str, CliError> {
+        self.get(name).ok_or_else(|| CliError::Usage(format!("missing --{}", name)))
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Usage(message)) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    let (command, rest) = args.split_first().ok_or_else(|| CliError::Usage("missing command".to_string()))?;
+    let allowed: &[&str] = match command.as_str() {
+        "setup" => &["circuit", "pk", "vk", "curve"],
+        "prove" => &["pk", "witness", "proof", "curve"],
+        "verify" => &["vk", "proof", "public", "curve"],
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        _ => return Err(CliError::Usage(format!("unknown command {}", command))),
+    };
+    let options = Options::parse(rest, allowed)?;
+
+    match options.get("curve").unwrap_or("bn254") {
+        "bn254" => dispatch::<Bn254>(command, &options),
+        "bls12_381" => dispatch::<Bls12_381>(command, &options),
+        curve => Err(CliError::Usage(format!("unknown curve {}", curve))),
+    }
+}
+
+fn dispatch<E: Engine>(command: &str, options: &Options) -> Result<(), CliError> {
+    match command {
+        "setup" => setup::<E>(options),
+        "prove" => prove::<E>(options),
+        _ => verify::<E>(options),
+    }
+}
+
+fn setup<E: Engine>(options: &Options) -> Result<(), CliError> {
+    let (pk_path, vk_path) = (options.required("pk")?, options.required("vk")?);
+    let r1cs: R1CS<E::Fr> = read(options.required("circuit")?)?.parse()?;
+
+    let pk: ProvingKey<E> = groth16::setup(r1cs)?;
+    write(pk_path, &to_text(&pk))?;
+    write(vk_path, &to_text(pk.vk()))?;
+    println!("Wrote proving key to {} and verifying key to {}", pk_path, vk_path);
+    Ok(())
+}
+
+fn prove<E: Engine>(options: &Options) -> Result<(), CliError> {
+    let proof_path = options.required("proof")?;
+    let pk: ProvingKey<E> = from_text(&read(options.required("pk")?)?)?;
+    let witness = parse_field_elements(&read(options.required("witness")?)?)?;
+
+    let proof = groth16::prove_witness(&pk, &witness)?;
+    write(proof_path, &to_text(&proof))?;
+    println!("Wrote proof to {}", proof_path);
+    Ok(())
+}
+
+fn verify<E: Engine>(options: &Options) -> Result<(), CliError> {
+    let vk: VerifyingKey<E> = from_text(&read(options.required("vk")?)?)?;
+    let proof: Proof<E> = from_text(&read(options.required("proof")?)?)?;
+    let public_inputs: Vec<E::Fr> = parse_field_elements(&read(options.required("public")?)?)?;
+
+    groth16::verify(&vk.prepare()?, &public_inputs, &proof)?;
+    println!("Proof verified!");
+    Ok(())
+}
+
+fn read(path: &str) -> Result<String, CliError> {
+    fs::read_to_string(path).map_err(|error| CliError::Io(path.to_string(), error))
+}
+
+fn write(path: &str, contents: &str) -> Result<(), CliError> {
+    fs::write(path, contents).map_err(|error| CliError::Io(path.to_string(), error))
 }
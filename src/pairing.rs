@@ -0,0 +1,303 @@
+use std::fmt::Debug;
+use std::ops::Mul;
+
+use crate::curve::{Affine, CurveParams};
+use crate::error::{Error, Result};
+use crate::field::Field;
+use crate::fp12::Fp12;
+use crate::fp2::{Fp2, Fp2Config};
+use crate::fp6::Fp6;
+
+// Where G2 sits relative to G1 on the sextic twist, which decides where line coefficients land in Fp12
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum TwistType {
+    // y^2 = x^3 + b / xi
+    D,
+    // y^2 = x^3 + b * xi
+    M,
+}
+
+// An affine point (x, y) on the twist, or a line's (lambda, lambda * x_T - y_T)
+pub type Fp2Pair<C> = (Fp2<C>, Fp2<C>);
+
+// What a BN or BLS12 curve supplies on top of its tower for the ate pairing
+pub trait PairingConfig: 'static + Copy + Clone + Debug + PartialEq + Eq {
+    type Fp2Config: Fp2Config;
+
+    type G1: CurveParams<Base = <Self::Fp2Config as Fp2Config>::Fp>;
+
+    type G2: CurveParams<Base = Fp2<Self::Fp2Config>>;
+
+    const TWIST_TYPE: TwistType;
+
+    // |x| for the curve parameter x
+    const X: u64;
+
+    const X_IS_NEGATIVE: bool;
+
+    // |loop count| of the Miller loop, negative exactly when x is
+    const ATE_LOOP_COUNT: u128;
+
+    // Points added to T after the loop, e.g. pi(Q) and -pi^2(Q) for BN curves
+    fn final_additions(_q: Fp2Pair<Self::Fp2Config>) -> Vec<Fp2Pair<Self::Fp2Config>> {
+        vec![]
+    }
+
+    // Raises f, already in the cyclotomic subgroup, to a multiple of (p^4 - p^2 + 1) / r coprime to r
+    fn final_exponentiation_hard_part(f: Fp12<Self::Fp2Config>) -> Fp12<Self::Fp2Config>;
+}
+
+// Target group, the order-r subgroup of Fp12*
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Gt<P: PairingConfig>(Fp12<P::Fp2Config>);
+
+impl<P: PairingConfig> Gt<P> {
+    pub fn identity() -> Gt<P> {
+        Gt(Fp12::one())
+    }
+
+    pub fn value(&self) -> Fp12<P::Fp2Config> {
+        self.0
+    }
+
+    pub fn pow(&self, exp: &[u64]) -> Gt<P> {
+        Gt(self.0.pow(exp))
+    }
+}
+
+impl<P: PairingConfig> Mul for Gt<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Gt(self.0 * rhs.0)
+    }
+}
+
+// Line coefficients (lambda, lambda * x_T - y_T) of every Miller loop step for a fixed Q,
+// so pairings against it only evaluate the lines at P
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Prepared<P: PairingConfig> {
+    coeffs: Vec<Fp2Pair<P::Fp2Config>>,
+    infinity: bool,
+}
+
+impl<P: PairingConfig> G2Prepared<P> {
+    pub fn is_identity(&self) -> bool {
+        self.infinity
+    }
+}
+
+impl<P: PairingConfig> TryFrom<&Affine<P::G2>> for G2Prepared<P> {
+    type Error = Error;
+
+    fn try_from(q: &Affine<P::G2>) -> Result<Self> {
+        prepare(q)
+    }
+}
+
+pub fn pairing<P: PairingConfig>(p: &Affine<P::G1>, q: &Affine<P::G2>) -> Result<Gt<P>> {
+    Ok(final_exponentiation(miller_loop::<P>(p, q)?))
+}
+
+// Product of pairings sharing a single final exponentiation
+pub fn multi_pairing<P: PairingConfig>(pairs: &[(&Affine<P::G1>, &G2Prepared<P>)]) -> Gt<P> {
+    let f = pairs.iter().fold(Fp12::one(), |f, (p, q)| f * miller_loop_prepared(p, q));
+    final_exponentiation(f)
+}
+
+pub fn miller_loop<P: PairingConfig>(p: &Affine<P::G1>, q: &Affine<P::G2>) -> Result<Fp12<P::Fp2Config>> {
+    Ok(miller_loop_prepared(p, &prepare::<P>(q)?))
+}
+
+// Ate Miller loop, with T kept in affine coordinates on the twist.
+// Vertical lines lie in a proper subfield and are dropped, the final exponentiation kills them.
+// For Q in G2, T never reaches a point with y = 0 or an addition with T = ±Q; an on-curve point
+// outside G2 can, and is rejected with NonInvertible.
+pub fn prepare<P: PairingConfig>(q: &Affine<P::G2>) -> Result<G2Prepared<P>> {
+    let mut coeffs = vec![];
+    if q.is_identity() {
+        return Ok(G2Prepared { coeffs, infinity: true });
+    }
+
+    let q_point = (q.x(), q.y());
+    let mut t = q_point;
+
+    let bits = 128 - P::ATE_LOOP_COUNT.leading_zeros();
+    for i in (0..bits - 1).rev() {
+        coeffs.push(doubling_step(&mut t)?);
+        if (P::ATE_LOOP_COUNT >> i) & 1 == 1 {
+            coeffs.push(addition_step(&mut t, q_point)?);
+        }
+    }
+
+    for point in P::final_additions(q_point) {
+        coeffs.push(addition_step(&mut t, point)?);
+    }
+    Ok(G2Prepared { coeffs, infinity: false })
+}
+
+// Replays the steps of prepare(), consuming one line per doubling or addition
+pub fn miller_loop_prepared<P: PairingConfig>(p: &Affine<P::G1>, q: &G2Prepared<P>) -> Fp12<P::Fp2Config> {
+    if p.is_identity() || q.is_identity() {
+        return Fp12::one();
+    }
+
+    let mut coeffs = q.coeffs.iter();
+    let mut f = Fp12::one();
+
+    let bits = 128 - P::ATE_LOOP_COUNT.leading_zeros();
+    for i in (0..bits - 1).rev() {
+        f = f.square() * line::<P>(coeffs.next().unwrap(), p);
+        if (P::ATE_LOOP_COUNT >> i) & 1 == 1 {
+            f = f * line::<P>(coeffs.next().unwrap(), p);
+        }
+    }
+
+    if P::X_IS_NEGATIVE {
+        f = f.conjugate();
+    }
+    // Whatever final_additions left
+    coeffs.fold(f, |f, coeff| f * line::<P>(coeff, p))
+}
+
+pub fn final_exponentiation<P: PairingConfig>(f: Fp12<P::Fp2Config>) -> Gt<P> {
+    // Easy part: f^((p^6 - 1)(p^2 + 1))
+    let f_inv = f.inverse().expect("Miller loop output is never zero");
+    let mut r = f.conjugate() * f_inv;
+    r = r.frobenius_map(2) * r;
+
+    Gt(P::final_exponentiation_hard_part(r))
+}
+
+// f^x for f in the cyclotomic subgroup, where conjugation inverts
+pub fn exp_by_x<P: PairingConfig>(f: Fp12<P::Fp2Config>) -> Fp12<P::Fp2Config> {
+    let result = f.pow(&[P::X]);
+    if P::X_IS_NEGATIVE {
+        result.conjugate()
+    } else {
+        result
+    }
+}
+
+// Line through T with the given slope, evaluated at P:
+// y_P - lambda * x_P * w + (lambda * x_T - y_T) * w^3 on a D-type twist,
+// scaled by w^3 to (lambda * x_T - y_T) - lambda * x_P * w^2 + y_P * w^3 on an M-type one
+fn line<P: PairingConfig>(&(lambda, c): &Fp2Pair<P::Fp2Config>, p: &Affine<P::G1>) -> Fp12<P::Fp2Config> {
+    let y_p = Fp2::new(p.y(), Field::zero());
+    let lambda_x_p = -lambda.scale(p.x());
+    match P::TWIST_TYPE {
+        TwistType::D => Fp12::new(
+            Fp6::new(y_p, Fp2::zero(), Fp2::zero()),
+            Fp6::new(lambda_x_p, c, Fp2::zero()),
+        ),
+        TwistType::M => Fp12::new(
+            Fp6::new(c, lambda_x_p, Fp2::zero()),
+            Fp6::new(Fp2::zero(), y_p, Fp2::zero()),
+        ),
+    }
+}
+
+fn doubling_step<P: Fp2Config>(t: &mut Fp2Pair<P>) -> Result<Fp2Pair<P>> {
+    let (x, y) = *t;
+    let x2 = x.square();
+    let lambda = (x2.double() + x2) * y.double().inverse().ok_or(Error::NonInvertible)?;
+    let x3 = lambda.square() - x.double();
+    let y3 = lambda * (x - x3) - y;
+    *t = (x3, y3);
+    Ok((lambda, lambda * x - y))
+}
+
+fn addition_step<P: Fp2Config>(t: &mut Fp2Pair<P>, q: Fp2Pair<P>) -> Result<Fp2Pair<P>> {
+    let (x, y) = *t;
+    let lambda = (q.1 - y) * (q.0 - x).inverse().ok_or(Error::NonInvertible)?;
+    let x3 = lambda.square() - x - q.0;
+    let y3 = lambda * (x - x3) - y;
+    *t = (x3, y3);
+    Ok((lambda, lambda * x - y))
+}
+
+// Checks shared by the BN254 and BLS12-381 pairing tests
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::curve::Projective;
+
+    fn generators<P: PairingConfig>() -> (Projective<P::G1>, Projective<P::G2>) {
+        (Projective::generator(), Projective::generator())
+    }
+
+    fn e<P: PairingConfig>(p: Projective<P::G1>, q: Projective<P::G2>) -> Gt<P> {
+        pairing::<P>(&p.to_affine(), &q.to_affine()).unwrap()
+    }
+
+    // Little-endian limbs of a big-endian hex integer
+    fn limbs_from_hex(hex: &str) -> Vec<u64> {
+        let mut limbs = vec![];
+        let mut end = hex.len();
+        while end > 0 {
+            let start = end.saturating_sub(16);
+            limbs.push(u64::from_str_radix(&hex[start..end], 16).unwrap());
+            end = start;
+        }
+        limbs
+    }
+
+    pub(crate) fn generators_in_r_torsion<P: PairingConfig>(order: &[u64]) {
+        let (g1, g2) = generators::<P>();
+        assert!(g1.to_affine().is_on_curve() && !g1.is_identity());
+        assert!(g2.to_affine().is_on_curve() && !g2.is_identity());
+        assert!(g1.mul_limbs(order).is_identity());
+        assert!(g2.mul_limbs(order).is_identity());
+    }
+
+    pub(crate) fn non_degenerate<P: PairingConfig>(order: &[u64]) {
+        let (g1, g2) = generators::<P>();
+        let gt = e::<P>(g1, g2);
+        assert_ne!(gt, Gt::identity());
+        assert_eq!(gt.pow(order), Gt::identity());
+        assert_eq!(e::<P>(Projective::identity(), g2), Gt::identity());
+        assert_eq!(e::<P>(g1, Projective::identity()), Gt::identity());
+    }
+
+    pub(crate) fn bilinear<P: PairingConfig>() {
+        let (g1, g2) = generators::<P>();
+        let (a, b) = (0x9e3779b97f4a7c15u64, 0xc2b2ae3d27d4eb4fu64);
+        let ab = a as u128 * b as u128;
+        let ab = [ab as u64, (ab >> 64) as u64];
+
+        let e_ab = e::<P>(g1.mul_limbs(&[a]), g2.mul_limbs(&[b]));
+        assert_eq!(e_ab, e::<P>(g1, g2).pow(&ab));
+        assert_eq!(e_ab, e::<P>(g1.mul_limbs(&ab), g2));
+        assert_eq!(e_ab, e::<P>(g1, g2.mul_limbs(&ab)));
+        assert_eq!(
+            e::<P>(g1.mul_limbs(&[a]), g2) * e::<P>(g1.mul_limbs(&[b]), g2),
+            e::<P>(g1.mul_limbs(&[a]) + g1.mul_limbs(&[b]), g2),
+        );
+    }
+
+    // The tower's Frobenius maps against raising to p, on elements taken from G2 and the Miller loop
+    pub(crate) fn frobenius_is_pow_p<P: PairingConfig>(characteristic: &[u64]) {
+        let (g1, g2) = generators::<P>();
+        let a = g2.to_affine().x();
+        assert_eq!(a.frobenius_map(1), a.pow(characteristic));
+
+        let c = miller_loop::<P>(&g1.to_affine(), &g2.to_affine()).unwrap();
+        let mut c_pow = c;
+        for power in 1..12 {
+            c_pow = c_pow.pow(characteristic);
+            assert_eq!(c.frobenius_map(power), c_pow);
+        }
+        assert_eq!(c_pow.pow(characteristic), c);
+    }
+
+    // final_exponentiation against the naive power, exponent = (p^12 - 1) / r times the hard part's cofactor
+    pub(crate) fn final_exponentiation_is_naive_pow<P: PairingConfig>(exponent_hex: &str) {
+        let exponent = limbs_from_hex(exponent_hex);
+        let (g1, g2) = generators::<P>();
+        let f = miller_loop::<P>(&g1.to_affine(), &g2.to_affine()).unwrap();
+        assert_eq!(final_exponentiation::<P>(f).value(), f.pow(&exponent));
+
+        let f = miller_loop::<P>(&g1.double().to_affine(), &g2.to_affine()).unwrap();
+        assert_eq!(final_exponentiation::<P>(f).value(), f.pow(&exponent));
+    }
+}
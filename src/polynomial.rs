@@ -0,0 +1,193 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::field::Field;
+
+// Dense univariate polynomial, coefficients little-endian with no trailing zeros
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial<F: Field> {
+    coeffs: Vec<F>,
+}
+
+impl<F: Field> Polynomial<F> {
+    pub fn new(mut coeffs: Vec<F>) -> Self {
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        Polynomial { coeffs }
+    }
+
+    pub fn zero() -> Self {
+        Polynomial { coeffs: vec![] }
+    }
+
+    pub fn constant(value: F) -> Self {
+        Polynomial::new(vec![value])
+    }
+
+    // x^n - 1
+    pub fn vanishing(n: usize) -> Self {
+        let mut coeffs = vec![F::zero(); n + 1];
+        coeffs[0] = -F::one();
+        coeffs[n] = coeffs[n] + F::one();
+        Polynomial::new(coeffs)
+    }
+
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    // The zero polynomial is given degree 0
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    pub fn leading_coeff(&self) -> F {
+        self.coeffs.last().copied().unwrap_or(F::zero())
+    }
+
+    // Horner's rule
+    pub fn evaluate(&self, x: F) -> F {
+        self.coeffs.iter().rev().fold(F::zero(), |acc, &c| acc * x + c)
+    }
+
+    pub fn scale(&self, scalar: F) -> Self {
+        Polynomial::new(self.coeffs.iter().map(|&c| c * scalar).collect())
+    }
+
+    // Long division, None when dividing by zero
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        let lead_inv = divisor.leading_coeff().inverse()?;
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return Some((Polynomial::zero(), self.clone()));
+        }
+
+        let shift = self.coeffs.len() - divisor.coeffs.len();
+        let mut quotient = vec![F::zero(); shift + 1];
+        let mut remainder = self.coeffs.clone();
+        for i in (0..=shift).rev() {
+            let factor = remainder[i + divisor.degree()] * lead_inv;
+            quotient[i] = factor;
+            for (j, &d) in divisor.coeffs.iter().enumerate() {
+                remainder[i + j] = remainder[i + j] - factor * d;
+            }
+        }
+        Some((Polynomial::new(quotient), Polynomial::new(remainder)))
+    }
+
+    // Lagrange interpolation through (x_i, y_i), None on repeated x or mismatched lengths
+    pub fn interpolate(x_points: &[F], y_points: &[F]) -> Option<Self> {
+        if x_points.len() != y_points.len() {
+            return None;
+        }
+
+        let mut result = Polynomial::zero();
+        for (i, (&x_i, &y_i)) in x_points.iter().zip(y_points.iter()).enumerate() {
+            let mut basis = Polynomial::constant(F::one());
+            let mut denominator = F::one();
+            for (j, &x_j) in x_points.iter().enumerate() {
+                if i != j {
+                    basis = basis * Polynomial::new(vec![-x_j, F::one()]);
+                    denominator = denominator * (x_i - x_j);
+                }
+            }
+            result = result + basis.scale(y_i * denominator.inverse()?);
+        }
+        Some(result)
+    }
+}
+
+impl<F: Field> Add for Polynomial<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut longer, shorter) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self.coeffs, rhs.coeffs)
+        } else {
+            (rhs.coeffs, self.coeffs)
+        };
+        for (a, b) in longer.iter_mut().zip(shorter) {
+            *a = *a + b;
+        }
+        Polynomial::new(longer)
+    }
+}
+
+impl<F: Field> Sub for Polynomial<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<F: Field> Mul for Polynomial<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut result = vec![F::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in rhs.coeffs.iter().enumerate() {
+                result[i + j] = result[i + j] + a * b;
+            }
+        }
+        Polynomial::new(result)
+    }
+}
+
+impl<F: Field> Neg for Polynomial<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Polynomial::new(self.coeffs.into_iter().map(|c| -c).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::Fr;
+
+    fn poly(coeffs: &[u64]) -> Polynomial<Fr> {
+        Polynomial::new(coeffs.iter().map(|&c| Fr::from_u64(c)).collect())
+    }
+
+    #[test]
+    fn div_rem_by_non_monic_divisor() {
+        // (2x^3 + 3x^2 + 5x + 7) = (3x + 1)·q + r
+        let dividend = poly(&[7, 5, 3, 2]);
+        let divisor = poly(&[1, 3]);
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert_eq!(quotient.degree(), 2);
+        assert_eq!(remainder.degree(), 0);
+        assert!(!remainder.is_zero());
+        assert_eq!(quotient * divisor + remainder, dividend);
+    }
+
+    #[test]
+    fn div_rem_by_zero_is_none() {
+        assert_eq!(poly(&[1, 2, 3]).div_rem(&Polynomial::zero()), None);
+    }
+
+    #[test]
+    fn interpolate_round_trip() {
+        let p = Polynomial::new((0..5).map(|_| Fr::random()).collect());
+        let xs: Vec<Fr> = (0..5).map(|_| Fr::random()).collect();
+        let ys: Vec<Fr> = xs.iter().map(|&x| p.evaluate(x)).collect();
+        assert_eq!(Polynomial::interpolate(&xs, &ys), Some(p));
+    }
+
+    #[test]
+    fn interpolate_rejects_repeated_x() {
+        let xs = [Fr::from_u64(1), Fr::from_u64(2), Fr::from_u64(1)];
+        let ys = [Fr::from_u64(4), Fr::from_u64(5), Fr::from_u64(6)];
+        assert_eq!(Polynomial::interpolate(&xs, &ys), None);
+        assert_eq!(Polynomial::interpolate(&xs[..2], &ys), None);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::curve::Projective;
+use crate::engine::Engine;
+
+// A Groth16 proof, A and C in G1 and B in G2
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Proof<E: Engine> {
+    a: Projective<E::G1>,
+    b: Projective<E::G2>,
+    c: Projective<E::G1>,
+}
+
+impl<E: Engine> Proof<E> {
+    pub fn new(a: Projective<E::G1>, b: Projective<E::G2>, c: Projective<E::G1>) -> Self {
+        Proof { a, b, c }
+    }
+
+    pub fn a(&self) -> Projective<E::G1> {
+        self.a
+    }
+
+    pub fn b(&self) -> Projective<E::G2> {
+        self.b
+    }
+
+    pub fn c(&self) -> Projective<E::G1> {
+        self.c
+    }
+
+    // Every element lies on its curve and in the order-r subgroup
+    pub fn is_valid(&self) -> bool {
+        self.a.is_on_curve()
+            && self.b.is_on_curve()
+            && self.c.is_on_curve()
+            && self.a.is_in_subgroup::<E::Fr>()
+            && self.b.is_in_subgroup::<E::Fr>()
+            && self.c.is_in_subgroup::<E::Fr>()
+    }
+}
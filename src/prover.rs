@@ -0,0 +1,55 @@
+use rand::RngCore;
+
+use crate::curve::{CurveParams, Projective};
+use crate::engine::Engine;
+use crate::error::{Error, Result};
+use crate::field::{Field, PrimeField};
+use crate::keys::ProvingKey;
+use crate::polynomial::Polynomial;
+use crate::proof::Proof;
+
+// Proof for the witness [1, public..., private...], blinded from the thread-local RNG.
+// Circuits enter through groth16::prove, which synthesizes the witness and checks it first.
+pub fn create_proof<E: Engine>(pk: &ProvingKey<E>, witness: &[E::Fr]) -> Result<Proof<E>> {
+    create_proof_with_rng(pk, witness, &mut rand::thread_rng())
+}
+
+// Prover with a caller-supplied source for the blinding factors r and s
+pub fn create_proof_with_rng<E: Engine, R: RngCore + ?Sized>(
+    pk: &ProvingKey<E>,
+    witness: &[E::Fr],
+    rng: &mut R,
+) -> Result<Proof<E>> {
+    let qap = pk.qap();
+    if witness.len() != qap.num_variables() {
+        return Err(Error::DimensionMismatch { expected: qap.num_variables(), found: witness.len() });
+    }
+    let (u, v, w) = qap.witness_polynomials(witness)?;
+    let h = qap.h(&u, &v, &w)?;
+    let private = &witness[qap.num_public() + 1..];
+    let r = E::Fr::random_with(rng);
+    let s = E::Fr::random_with(rng);
+
+    // A = alpha + U(tau) + r * delta, B = beta + V(tau) + s * delta
+    let a1 = pk.alpha_g1() + commit(pk.tau_g1(), &u)? + pk.delta_g1() * r;
+    let b2 = pk.beta_g2() + commit(pk.tau_g2(), &v)? + pk.delta_g2() * s;
+    let b1 = pk.beta_g1() + commit(pk.tau_g1(), &v)? + pk.delta_g1() * s;
+
+    // C = L + h(tau) * t(tau) / delta + s * A + r * B - r * s * delta
+    let c1 = Projective::msm(pk.l(), private)?
+        + commit(pk.h(), &h)?
+        + a1 * s
+        + b1 * r
+        - pk.delta_g1() * (r * s);
+
+    Ok(Proof::new(a1, b2, c1))
+}
+
+// Sum of p_i * powers[i], the polynomial evaluated at the secret in the exponent
+fn commit<C: CurveParams, F: PrimeField>(powers: &[Projective<C>], poly: &Polynomial<F>) -> Result<Projective<C>> {
+    let coeffs = poly.coeffs();
+    if coeffs.len() > powers.len() {
+        return Err(Error::DimensionMismatch { expected: powers.len(), found: coeffs.len() });
+    }
+    Projective::msm(&powers[..coeffs.len()], coeffs)
+}
@@ -1,160 +1,161 @@
-use crate::field::{FieldElement64, MODULUS64};
-use crate::vector::{i128_to_u64_matrix, i128_to_u64_vec, i64_to_i128_matrix, i64_to_i128_vec, MyVec};
-use crate::r1cs::{witness_multiply, R1CS};
-
-pub struct QAP {
-    u: Vec<FieldElement64>,
-    v: Vec<FieldElement64>,
-    w: Vec<FieldElement64>,
-    t: u64,
-    h: Vec<FieldElement64>,
+use crate::domain::EvaluationDomain;
+use crate::error::{Error, Result, UnsatisfiedConstraint};
+use crate::field::{FftField, Field, PrimeField};
+use crate::polynomial::Polynomial;
+use crate::r1cs::R1CS;
+use crate::sparse::SparseMatrix;
+
+// Kept in evaluation form: constraint j sits at w^j, so u_i(w^j) = U[j][i] and likewise for V and W.
+// The per-variable polynomials are never built; the prover interpolates only the witness
+// combinations and the setup evaluates them at tau through the Lagrange basis.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QAP<F: FftField> {
+    u: SparseMatrix<F>,
+    v: SparseMatrix<F>,
+    w: SparseMatrix<F>,
+    domain: EvaluationDomain<F>,
+    num_public: usize,
 }
 
-impl QAP {
-    pub fn from_r1cs(r1cs: R1CS, witness: Vec<i64>) -> Self {
-        let mut l_vec = witness_multiply(r1cs.left(), witness.clone());
-        let mut r_vec = witness_multiply(r1cs.right(), witness.clone());
-        let mut o_vec = witness_multiply(r1cs.output(), witness.clone());
-        let t_val = o_vec.len();
-
-        let t_poly = generate_t(t_val);
-        let uv = multiply_polynomials(&l_vec, &r_vec);
-        let uvw = subtract_polynomials(&uv, &o_vec);
-        let (mut h, _) = divide_polynomials(&uvw, &t_poly);
-                
-        let left = FieldElement64::convert1d(&mut field_compatible_vector(&mut l_vec));
-        let right = FieldElement64::convert1d(&mut field_compatible_vector(&mut r_vec));
-        let output = FieldElement64::convert1d(&mut field_compatible_vector(&mut o_vec));
-        let h_vec = FieldElement64::convert1d(&mut field_compatible_vector(&mut h));
-
-        println!("QAP from R1CS done!");
-
-        Self {
-            u: left,
-            v: right,
-            w: output,
-            t: t_val as u64,
-            h: h_vec,
+impl<F: FftField> QAP<F> {
+    // Rebuilds a QAP from its parts, e.g. when loading a proving key
+    pub fn new(
+        u: SparseMatrix<F>,
+        v: SparseMatrix<F>,
+        w: SparseMatrix<F>,
+        domain: EvaluationDomain<F>,
+        num_public: usize,
+    ) -> Result<Self> {
+        for matrix in [&v, &w] {
+            if matrix.num_columns() != u.num_columns() {
+                return Err(Error::DimensionMismatch { expected: u.num_columns(), found: matrix.num_columns() });
+            }
+            if matrix.num_rows() != u.num_rows() {
+                return Err(Error::DimensionMismatch { expected: u.num_rows(), found: matrix.num_rows() });
+            }
         }
-
+        if u.num_rows() > domain.size() {
+            return Err(Error::DimensionMismatch { expected: domain.size(), found: u.num_rows() });
+        }
+        if num_public >= u.num_columns() {
+            return Err(Error::DimensionMismatch { expected: u.num_columns(), found: num_public + 1 });
+        }
+        Ok(Self { u, v, w, domain, num_public })
     }
 
-    // Prover
-    pub fn evaluate(&self, srs_values: [Vec<FieldElement64>; 3]) -> [FieldElement64; 3] {
-        let a1 = inner_product(&self.u, &srs_values[0]).unwrap();
-        let b2 = inner_product(&self.v, &srs_values[1]).unwrap();
-        let c1 =  inner_product(&self.w, &srs_values[0]).unwrap() + inner_product(&self.h, &srs_values[2]).unwrap();
-
-        [a1, b2, c1]
-    }
+    pub fn from_r1cs(r1cs: R1CS<F>) -> Result<Self> {
+        let num_public = r1cs.num_public();
+        let (mut u, mut v, mut w) = r1cs.into_matrices();
+
+        // x_i * 0 = 0 for the constant one and every public input gives each u_i a term no other
+        // variable has, so the IC points are independent and a proof fixes the public inputs
+        // even when they appear in no constraint
+        for i in 0..=num_public {
+            u.push_row([(i, F::one())])?;
+            v.push_row([])?;
+            w.push_row([])?;
+        }
 
-    // Verifier
-    pub fn verify(prover: [FieldElement64; 3], srs_values: [Vec<FieldElement64>; 3]) -> () {
-        assert_eq!(prover[0] * prover[1], prover[2] * srs_values[1][0]);
+        let rows = u.num_rows();
+        let domain = EvaluationDomain::<F>::new(rows).ok_or(Error::DomainTooLarge(rows))?;
+        Ok(Self { u, v, w, domain, num_public })
     }
 
+    // Size n of the domain, t(x) = x^n - 1
     pub fn t_val(&self) -> u64 {
-        self.t
+        self.domain.size() as u64
     }
-}
 
-// Polynomial operations
+    // Public inputs, not counting the constant one
+    pub fn num_public(&self) -> usize {
+        self.num_public
+    }
 
-fn generate_t(n: usize) -> Vec<i64> {
-    let mut t = vec![1];
-    for i in 1..=n {
-        t = multiply_polynomials(&t, &vec![-(i as i64), 1]);
+    // Witness length, including the constant one
+    pub fn num_variables(&self) -> usize {
+        self.u.num_columns()
     }
-    t
-}
 
-fn multiply_polynomials(a: &Vec<i64>, b: &Vec<i64>) -> Vec<i64> {
-    let mut result = vec![0; a.len() + b.len() - 1];
-    for (i, &coeff_a) in a.iter().enumerate() {
-        for (j, &coeff_b) in b.iter().enumerate() {
-            result[i + j] += coeff_a * coeff_b;
-        }
+    // u_i(w^j) at row j, column i
+    pub fn u(&self) -> &SparseMatrix<F> {
+        &self.u
     }
-    result
-}
 
-fn subtract_polynomials(a: &Vec<i64>, b: &Vec<i64>) -> Vec<i64> {
-    let max_len = a.len().max(b.len());
-    let mut result = vec![0; max_len];
-    for i in 0..max_len {
-        let coeff_a = *a.get(i).unwrap_or(&0);
-        let coeff_b = *b.get(i).unwrap_or(&0);
-        result[i] = coeff_a - coeff_b;
+    pub fn v(&self) -> &SparseMatrix<F> {
+        &self.v
     }
-    result
-}
 
-fn divide_polynomials(dividend: &Vec<i64>, divisor: &Vec<i64>) -> (Vec<i64>, Vec<i64>) {
-    let mut quotient = vec![0; dividend.len().saturating_sub(divisor.len()) + 1];
-    let mut remainder = dividend.clone();
+    pub fn w(&self) -> &SparseMatrix<F> {
+        &self.w
+    }
 
-    for i in (0..=dividend.len().saturating_sub(divisor.len())).rev() {
-        let lead_coeff = remainder[i + divisor.len() - 1] / divisor[divisor.len() - 1];
-        quotient[i] = lead_coeff;
+    // u_i(tau), v_i(tau) and w_i(tau) for every variable i
+    pub fn evaluate_at(&self, tau: F) -> (Vec<F>, Vec<F>, Vec<F>) {
+        let lagrange = self.domain.evaluate_all_lagrange_coefficients(tau);
+        (
+            evaluate_columns(&self.u, &lagrange),
+            evaluate_columns(&self.v, &lagrange),
+            evaluate_columns(&self.w, &lagrange),
+        )
+    }
 
-        for j in 0..divisor.len() {
-            remainder[i + j] -= lead_coeff * divisor[j];
+    // U = sum a_i u_i, V = sum a_i v_i, W = sum a_i w_i for the witness a,
+    // interpolated from their evaluations U·a, V·a, W·a over the domain.
+    // Those evaluations are the constraints themselves, so any that fail are reported by row.
+    pub fn witness_polynomials(&self, witness: &[F]) -> Result<(Polynomial<F>, Polynomial<F>, Polynomial<F>)> {
+        let u = self.u.mul_vector(witness)?;
+        let v = self.v.mul_vector(witness)?;
+        let w = self.w.mul_vector(witness)?;
+        let unsatisfied = unsatisfied_rows(&u, &v, &w);
+        if !unsatisfied.is_empty() {
+            return Err(Error::UnsatisfiedConstraints(unsatisfied));
         }
+        Ok((
+            Polynomial::new(self.domain.ifft(&u)?),
+            Polynomial::new(self.domain.ifft(&v)?),
+            Polynomial::new(self.domain.ifft(&w)?),
+        ))
     }
 
-    (quotient, remainder)
-}
-
-pub fn falling_factorial(x: FieldElement64, n: u64) -> FieldElement64 {
-    let mut value = FieldElement64::new(1);
-    for i in 1..n+1 {
-        value = value * (x - FieldElement64::new(i));
+    // h = (U·V - W) / t, evaluated pointwise on a coset where t has no roots.
+    // Exact only for polynomials from witness_polynomials, which has already checked
+    // that U·V - W vanishes on the domain.
+    pub fn h(&self, u: &Polynomial<F>, v: &Polynomial<F>, w: &Polynomial<F>) -> Result<Polynomial<F>> {
+        let u_coset = self.domain.coset_fft(u.coeffs())?;
+        let v_coset = self.domain.coset_fft(v.coeffs())?;
+        let w_coset = self.domain.coset_fft(w.coeffs())?;
+        let mut h_coset: Vec<F> = u_coset.iter()
+            .zip(v_coset.iter())
+            .zip(w_coset.iter())
+            .map(|((&u, &v), &w)| u * v - w)
+            .collect();
+        self.domain.divide_by_vanishing_poly_on_coset_in_place(&mut h_coset);
+        Ok(Polynomial::new(self.domain.coset_ifft(&h_coset)?))
     }
-    value
-}
-
-pub fn field_compatible_vector(input: &mut Vec<i64>) -> Vec<u64> {
-    let mut vectorop = i64_to_i128_vec(input);
-    vectorop = MyVec(vectorop) % (MODULUS64 as i128);
-    vectorop = MyVec(vectorop) + (MODULUS64 as i128);
-    i128_to_u64_vec(&mut vectorop)
-}
-
-pub fn field_compatible_matrix(input: &mut Vec<Vec<i64>>) -> Vec<Vec<u64>> {
-    let mut matrixop = i64_to_i128_matrix(input);
-    matrixop = MyVec(matrixop) % (MODULUS64 as i128);
-    matrixop = MyVec(matrixop) + (MODULUS64 as i128);
-    i128_to_u64_matrix(&mut matrixop)
 }
 
-pub fn inner_product(vec1: &Vec<FieldElement64>, vec2: &Vec<FieldElement64>) -> Option<FieldElement64> {
-    assert_eq!(vec1.len(), vec2.len());
-    let mut result = FieldElement64::new(0);
-
-    for (a, b) in vec1.iter().zip(vec2.iter()) {
-        result = result + (*a * *b);
-    }
-    Some(result)
+// Rows j with U·a[j] * V·a[j] != W·a[j]; the QAP keeps no constraint names
+fn unsatisfied_rows<F: PrimeField>(u: &[F], v: &[F], w: &[F]) -> Vec<UnsatisfiedConstraint> {
+    (0..u.len())
+        .filter(|&j| u[j] * v[j] != w[j])
+        .map(|j| UnsatisfiedConstraint {
+            index: j,
+            name: None,
+            left: u[j].to_string(),
+            right: v[j].to_string(),
+            output: w[j].to_string(),
+        })
+        .collect()
 }
 
-pub fn lagrange_interpolation(x: FieldElement64, x_points: &Vec<FieldElement64>, y_points: &Vec<FieldElement64>) -> Option<FieldElement64> {
-    if x_points.is_empty() {
-        return None
-    }
-
-    let mut result = FieldElement64::new(0);
-    for i in 0..x_points.len() {
-        let mut term = y_points[i];
-        for j in 0..x_points.len() {
-            if i!=j {
-                let num = x - x_points[j];
-                let mut den = x_points[i] - x_points[j];
-                den = FieldElement64::inverse(den);
-                term = term * num * den;
-            }
+// sum_j M[j][i] * L_j(tau) for every column i, touching only the stored entries
+fn evaluate_columns<F: Field>(matrix: &SparseMatrix<F>, lagrange: &[F]) -> Vec<F> {
+    let mut evals = vec![F::zero(); matrix.num_columns()];
+    for (row, &l) in matrix.rows().zip(lagrange) {
+        for &(column, value) in row {
+            evals[column] = evals[column] + value * l;
         }
-        result = result + term;
     }
-
-    Some(result)
-}
\ No newline at end of file
+    evals
+}
@@ -1,119 +1,422 @@
-pub struct Element {
+use std::fmt;
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::error::{Error, Result, UnsatisfiedConstraint};
+use crate::field::{Field, PrimeField};
+use crate::serialize::parse_field_element;
+use crate::sparse::SparseMatrix;
+
+pub struct Element<F: Field> {
     variable: usize,
-    coeff: i64,
+    coeff: F,
+}
+
+// Sum of coeff * w_variable terms; repeated variables add up.
+// Variables are witness indices unless a ConstraintSystem is still numbering them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinearCombination<F: Field, V = usize> {
+    terms: Vec<(V, F)>,
+}
+
+// A * B = C over linear combinations of the witness
+pub struct Constraint<F: Field, V = usize> {
+    a: LinearCombination<F, V>,
+    b: LinearCombination<F, V>,
+    c: LinearCombination<F, V>,
+    name: Option<ConstraintName>,
 }
 
-pub struct Constraint {
-    x: Element,
-    y: Element,
-    z: Element,
+// Where a constraint came from: its label, if any, under the namespaces open when it was enforced
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintName {
+    namespace: Vec<String>,
+    label: Option<String>,
 }
 
-pub struct R1CS {
-    l: Vec<Vec<i64>>,
-    r: Vec<Vec<i64>>,
-    o: Vec<Vec<i64>>,
+// Coefficients live in F, so satisfiability is checked modulo p.
+// L, R and O are sparse, one row per constraint and one column per variable.
+pub struct R1CS<F: Field> {
+    l: SparseMatrix<F>,
+    r: SparseMatrix<F>,
+    o: SparseMatrix<F>,
+    // Variables 1..=num_public are known to the verifier, variable 0 is the constant one
+    num_public: usize,
+    names: Vec<Option<ConstraintName>>,
 }
 
-impl Element {
-    pub fn new(var: usize, coeff: i64) -> Element{
+impl<F: Field> Element<F> {
+    pub fn new(var: usize, coeff: F) -> Element<F> {
         Self {
             variable: var,
-            coeff: coeff,
+            coeff,
         }
     }
 }
 
-impl Constraint {
-    pub fn new(e1: Element, e2: Element, e3: Element) -> Constraint {
+impl<F: Field, V> LinearCombination<F, V> {
+    pub fn new(terms: Vec<(V, F)>) -> Self {
+        LinearCombination { terms }
+    }
+
+    pub fn zero() -> Self {
+        LinearCombination { terms: vec![] }
+    }
+
+    // Appends coeff * w_variable
+    pub fn term(mut self, variable: V, coeff: F) -> Self {
+        self.terms.push((variable, coeff));
+        self
+    }
+
+    pub fn terms(&self) -> &[(V, F)] {
+        &self.terms
+    }
+
+    // Renames every variable, keeping the coefficients
+    pub fn map<W>(self, mut f: impl FnMut(V) -> W) -> LinearCombination<F, W> {
+        LinearCombination::new(self.terms.into_iter().map(|(variable, coeff)| (f(variable), coeff)).collect())
+    }
+}
+
+impl<F: Field, V> Default for LinearCombination<F, V> {
+    fn default() -> Self {
+        LinearCombination::zero()
+    }
+}
+
+impl<F: Field> From<Element<F>> for LinearCombination<F> {
+    fn from(element: Element<F>) -> Self {
+        LinearCombination::new(vec![(element.variable, element.coeff)])
+    }
+}
+
+impl<F: Field, V> Add for LinearCombination<F, V> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.terms.extend(rhs.terms);
+        self
+    }
+}
+
+impl<F: Field> Add<Element<F>> for LinearCombination<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Element<F>) -> Self::Output {
+        self.term(rhs.variable, rhs.coeff)
+    }
+}
+
+impl<F: Field, V> Constraint<F, V> {
+    pub fn new(
+        a: impl Into<LinearCombination<F, V>>,
+        b: impl Into<LinearCombination<F, V>>,
+        c: impl Into<LinearCombination<F, V>>,
+    ) -> Constraint<F, V> {
+        Constraint {
+            a: a.into(),
+            b: b.into(),
+            c: c.into(),
+            name: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: ConstraintName) -> Constraint<F, V> {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn name(&self) -> Option<&ConstraintName> {
+        self.name.as_ref()
+    }
+
+    // Renames every variable on all three sides
+    pub fn map<W>(self, mut f: impl FnMut(V) -> W) -> Constraint<F, W> {
         Constraint {
-            x: e1,
-            y: e2,
-            z: e3,
+            a: self.a.map(&mut f),
+            b: self.b.map(&mut f),
+            c: self.c.map(&mut f),
+            name: self.name,
         }
     }
 }
 
-impl R1CS {
-    pub fn blank(witness: Vec<i64>, eqn_count: Option<usize>) -> R1CS {
-        let witnesses = witness.len();
+impl ConstraintName {
+    pub fn new(namespace: Vec<String>, label: Option<String>) -> Self {
+        ConstraintName { namespace, label }
+    }
+
+    // Outermost namespace first
+    pub fn namespace(&self) -> &[String] {
+        &self.namespace
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
+
+// namespace/.../label
+impl fmt::Display for ConstraintName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path: Vec<&str> = self.namespace.iter().map(String::as_str).chain(self.label()).collect();
+        write!(f, "{}", path.join("/"))
+    }
+}
+
+impl<F: Field> R1CS<F> {
+    pub fn blank(num_variables: usize, eqn_count: Option<usize>) -> R1CS<F> {
         let eqn_count = eqn_count.unwrap_or(1);
+        let mut matrix = SparseMatrix::new(num_variables);
+        for _ in 0..eqn_count {
+            matrix.push_row([]).expect("Empty rows fit any width");
+        }
         R1CS {
-            l: vec![vec![0; witnesses]; eqn_count],
-            r: vec![vec![0; witnesses]; eqn_count],
-            o: vec![vec![0; witnesses]; eqn_count],
+            l: matrix.clone(),
+            r: matrix.clone(),
+            o: matrix,
+            num_public: 0,
+            names: vec![None; eqn_count],
         }
     }
 
-    pub fn new(constraints: Vec<Constraint>, witness: Vec<i64>) -> R1CS {
-        let witnesses = witness.len();
-        let eqn_count = constraints.len();
-        let mut left: Vec<Vec<i64>> = vec![vec![0; witnesses]; eqn_count];
-        let mut right: Vec<Vec<i64>> = vec![vec![0; witnesses]; eqn_count];
-        let mut out: Vec<Vec<i64>> = vec![vec![0; witnesses]; eqn_count];
-        let mut count: usize = 0;
+    // Constraints over a witness of num_variables, the constant one included
+    pub fn new(constraints: Vec<Constraint<F>>, num_variables: usize) -> Result<R1CS<F>> {
+        let mut left = SparseMatrix::new(num_variables);
+        let mut right = SparseMatrix::new(num_variables);
+        let mut out = SparseMatrix::new(num_variables);
+        let mut names = Vec::with_capacity(constraints.len());
 
         for constraint in constraints {
-            left[count][constraint.x.variable] = constraint.x.coeff;
-            right[count][constraint.y.variable] = constraint.y.coeff;
-            out[count][constraint.z.variable] = constraint.z.coeff;
-            count += 1;
-        };
+            left.push_row(constraint.a.terms)?;
+            right.push_row(constraint.b.terms)?;
+            out.push_row(constraint.c.terms)?;
+            names.push(constraint.name);
+        }
 
-        Self {
+        Ok(Self {
             l: left,
             r: right,
             o: out,
+            num_public: 0,
+            names,
+        })
+    }
+
+    // Declares the first count variables after the constant one as public inputs
+    pub fn with_public_inputs(mut self, count: usize) -> Result<R1CS<F>> {
+        if count >= self.num_variables() {
+            return Err(Error::DimensionMismatch { expected: self.num_variables(), found: count + 1 });
         }
+        self.num_public = count;
+        Ok(self)
+    }
+
+    // Witness length, including the constant one
+    pub fn num_variables(&self) -> usize {
+        self.l.num_columns()
     }
 
-    pub fn left(&self) -> Vec<Vec<i64>> {
-        self.l.clone()
+    pub fn num_public(&self) -> usize {
+        self.num_public
     }
 
-    pub fn right(&self) -> Vec<Vec<i64>> {
-        self.r.clone()
+    pub fn num_constraints(&self) -> usize {
+        self.l.num_rows()
     }
 
-    pub fn output(&self) -> Vec<Vec<i64>> {
-        self.o.clone()
+    pub fn left(&self) -> &SparseMatrix<F> {
+        &self.l
     }
 
-    pub fn verify(&self, witness: Vec<i64>) {
-        assert_eq!(self.l[0].len(), witness.len(), "Matrix L and witness size mismatch!");
-        assert_eq!(self.r[0].len(), witness.len(), "Matrix R and witness size mismatch!");
-        assert_eq!(self.o[0].len(), witness.len(), "Matrix O and witness size mismatch!");
-    
-        let l_mult = witness_multiply(self.l.clone(), witness.clone());
-        let r_mult = witness_multiply(self.r.clone(), witness.clone());
-        let o_mult = witness_multiply(self.o.clone(), witness.clone());
-        let lhs = hadamard_multiply(l_mult, r_mult);
-    
-        assert_eq!(lhs, o_mult, "R1CS constraints not satisfied!");
-        println!("R1CS constraints satisfied");
-    }    
+    pub fn right(&self) -> &SparseMatrix<F> {
+        &self.r
+    }
+
+    pub fn output(&self) -> &SparseMatrix<F> {
+        &self.o
+    }
+
+    // L, R and O, consuming the R1CS
+    pub fn into_matrices(self) -> (SparseMatrix<F>, SparseMatrix<F>, SparseMatrix<F>) {
+        (self.l, self.r, self.o)
+    }
+
+    // Name of constraint i, when it was given one
+    pub fn name(&self, i: usize) -> Option<&ConstraintName> {
+        self.names[i].as_ref()
+    }
 }
 
-pub fn witness_multiply(matrix: Vec<Vec<i64>>, witness: Vec<i64>) -> Vec<i64>{
-    let mut vector: Vec<i64> = vec![];
-    for rows in matrix {
-        let mut count = 0;
-        let mut sum: Vec<i64> = vec![];
-        while count < witness.len() {
-            sum.push(rows[count] * witness[count]);
-            count += 1;
+impl<F: PrimeField> R1CS<F> {
+    // Checks L·w ∘ R·w = O·w in F, reporting every constraint that fails
+    pub fn verify(&self, witness: &[F]) -> Result<()> {
+        let l_mult = self.l.mul_vector(witness)?;
+        let r_mult = self.r.mul_vector(witness)?;
+        let o_mult = self.o.mul_vector(witness)?;
+
+        let unsatisfied: Vec<UnsatisfiedConstraint> = (0..self.num_constraints())
+            .filter(|&i| l_mult[i] * r_mult[i] != o_mult[i])
+            .map(|i| UnsatisfiedConstraint {
+                index: i,
+                name: self.names[i].clone(),
+                left: l_mult[i].to_string(),
+                right: r_mult[i].to_string(),
+                output: o_mult[i].to_string(),
+            })
+            .collect();
+        if !unsatisfied.is_empty() {
+            return Err(Error::UnsatisfiedConstraints(unsatisfied));
         }
-        vector.push(sum.into_iter().sum());
+        Ok(())
     }
-    vector
 }
 
-fn hadamard_multiply(m1: Vec<i64>, m2: Vec<i64>) -> Vec<i64>{
-    let mut vector: Vec<i64> = vec![];
-    let mut count = 0;
-    while count < m1.len() {
-        vector.push(m1[count] * m2[count]);
-        count += 1;
+// Text circuit format, one statement per line and # for comments:
+//   variables 5
+//   public 1
+//   w0 * w1 = w2
+//   2*w2 * w3 = 3*w4
+//   w1 + w2 * w3 = w4 - 3*w0
+// Each side of a constraint is a sum of terms, so the last line reads (w1 + w2) * w3 = w4 - 3
+impl<F: Field> FromStr for R1CS<F> {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<R1CS<F>> {
+        let mut variables = None;
+        let mut public = 0;
+        let mut constraints = vec![];
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let parse_error = |message: &str| Error::Parse(format!("line {}: {}", number + 1, message));
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                [] => {}
+                ["variables", count] => {
+                    variables = Some(count.parse().map_err(|_| parse_error("invalid variable count"))?);
+                }
+                ["public", count] => {
+                    public = count.parse().map_err(|_| parse_error("invalid public input count"))?;
+                }
+                _ => {
+                    let times = tokens.iter().position(|&token| token == "*");
+                    let equals = tokens.iter().position(|&token| token == "=");
+                    let (times, equals) = match (times, equals) {
+                        (Some(times), Some(equals)) if times < equals => (times, equals),
+                        _ => return Err(parse_error("expected `variables n`, `public n` or `a * b = c`")),
+                    };
+                    let lc = |tokens: &[&str]| parse_linear_combination(tokens).ok_or_else(|| parse_error("invalid linear combination"));
+                    constraints.push(Constraint::new(
+                        lc(&tokens[..times])?,
+                        lc(&tokens[times + 1..equals])?,
+                        lc(&tokens[equals + 1..])?,
+                    ));
+                }
+            }
+        }
+
+        let variables = variables.ok_or_else(|| Error::Parse("missing `variables` line".to_string()))?;
+        R1CS::new(constraints, variables)?.with_public_inputs(public)
+    }
+}
+
+// term (+|- term)*
+fn parse_linear_combination<F: Field>(tokens: &[&str]) -> Option<LinearCombination<F>> {
+    let (first, rest) = tokens.split_first()?;
+    let mut lc = LinearCombination::zero() + parse_term(first)?;
+    for pair in rest.chunks(2) {
+        let term: Element<F> = parse_term(pair.get(1)?)?;
+        let coeff = match pair[0] {
+            "+" => term.coeff,
+            "-" => -term.coeff,
+            _ => return None,
+        };
+        lc = lc.term(term.variable, coeff);
+    }
+    Some(lc)
+}
+
+// coeff*wN, or wN for a coefficient of one
+fn parse_term<F: Field>(token: &str) -> Option<Element<F>> {
+    let (coeff, variable) = match token.split_once('*') {
+        Some((coeff, variable)) => (parse_field_element(coeff).ok()?, variable),
+        None => (F::one(), token),
+    };
+    Some(Element::new(variable.strip_prefix('w')?.parse().ok()?, coeff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::Fr;
+
+    fn fr(value: u64) -> Fr {
+        Fr::from_u64(value)
+    }
+
+    fn parse_error(text: &str) -> String {
+        match text.parse::<R1CS<Fr>>() {
+            Err(Error::Parse(message)) => message,
+            Err(other) => panic!("expected a parse error, got {}", other),
+            Ok(_) => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn parses_the_documented_example() {
+        let text = "\
+            # (x + y) * z = w - 3\n\
+            variables 5\n\
+            public 1\n\
+            w0 * w1 = w2\n\
+            2*w2 * w3 = 3*w4\n\
+            w1 + w2 * w3 = w4 - 3*w0  # sums on the left\n";
+        let r1cs: R1CS<Fr> = text.parse().unwrap();
+        assert_eq!(r1cs.num_variables(), 5);
+        assert_eq!(r1cs.num_public(), 1);
+        assert_eq!(r1cs.num_constraints(), 3);
+        assert_eq!(r1cs.left().row(1), &[(2, fr(2))]);
+        assert_eq!(r1cs.output().row(1), &[(4, fr(3))]);
+        assert_eq!(r1cs.left().row(2), &[(1, fr(1)), (2, fr(1))]);
+        assert_eq!(r1cs.right().row(2), &[(3, fr(1))]);
+        assert_eq!(r1cs.output().row(2), &[(0, -fr(3)), (4, fr(1))]);
+
+        // The first two constraints hold, but (2 + 2) * 3 = 4 - 3 does not
+        let witness = [fr(1), fr(2), fr(2), fr(3), fr(4)];
+        match r1cs.verify(&witness) {
+            Err(Error::UnsatisfiedConstraints(unsatisfied)) => {
+                assert_eq!(unsatisfied.len(), 1);
+                assert_eq!(unsatisfied[0].index, 2);
+                assert_eq!(unsatisfied[0].left, "4");
+                assert_eq!(unsatisfied[0].right, "3");
+                assert_eq!(unsatisfied[0].output, "1");
+            }
+            other => panic!("expected an unsatisfied constraint, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        assert_eq!(parse_error("public 1\nw0 * w1 = w1\n"), "missing `variables` line");
+        assert_eq!(parse_error("variables many\n"), "line 1: invalid variable count");
+        assert_eq!(parse_error("variables 3\npublic -1\n"), "line 2: invalid public input count");
+        assert_eq!(parse_error("variables 3\nw0 = w1 * w2\n"), "line 2: expected `variables n`, `public n` or `a * b = c`");
+        assert_eq!(parse_error("variables 3\nw0 * x1 = w2\n"), "line 2: invalid linear combination");
+        assert_eq!(parse_error("variables 3\nw0 * w1 + = w2\n"), "line 2: invalid linear combination");
+        assert_eq!(parse_error("variables 3\nw0 * w1 = w2 w1\n"), "line 2: invalid linear combination");
+    }
+
+    #[test]
+    fn rejects_out_of_range_variables_and_public_counts() {
+        assert!(matches!(
+            "variables 3\nw0 * w1 = w3\n".parse::<R1CS<Fr>>(),
+            Err(Error::DimensionMismatch { expected: 3, found: 4 })
+        ));
+        assert!(matches!(
+            "variables 3\npublic 3\nw0 * w1 = w2\n".parse::<R1CS<Fr>>(),
+            Err(Error::DimensionMismatch { expected: 3, found: 4 })
+        ));
     }
-    vector
 }
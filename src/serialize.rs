@@ -0,0 +1,466 @@
+use std::iter::Peekable;
+use std::str::SplitWhitespace;
+
+use crate::curve::{Affine, CurveParams, Projective};
+use crate::domain::EvaluationDomain;
+use crate::engine::Engine;
+use crate::error::{Error, Result};
+use crate::field::{FftField, Field};
+use crate::fp::{Fp, FpConfig};
+use crate::fp2::{Fp2, Fp2Config};
+use crate::keys::{ProvingKey, VerifyingKey};
+use crate::polynomial::Polynomial;
+use crate::proof::Proof;
+use crate::qap::QAP;
+use crate::sparse::SparseMatrix;
+
+// Whitespace-separated text encoding for keys and proofs.
+// Field elements are written as big-endian hex of their canonical value.
+pub trait Encode: Sized {
+    fn encode(&self, writer: &mut Writer);
+
+    fn decode(reader: &mut Reader) -> Result<Self>;
+}
+
+#[derive(Default)]
+pub struct Writer {
+    out: String,
+}
+
+pub struct Reader<'a> {
+    tokens: Peekable<SplitWhitespace<'a>>,
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        Writer::default()
+    }
+
+    pub fn token(&mut self, token: &str) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push(' ');
+        }
+        self.out.push_str(token);
+    }
+
+    pub fn newline(&mut self) {
+        self.out.push('\n');
+    }
+
+    pub fn finish(mut self) -> String {
+        if !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+        self.out
+    }
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Reader { tokens: text.split_whitespace().peekable() }
+    }
+
+    pub fn token(&mut self) -> Result<&'a str> {
+        self.tokens.next().ok_or_else(|| Error::Parse("unexpected end of input".to_string()))
+    }
+
+    pub fn expect(&mut self, expected: &str) -> Result<()> {
+        let token = self.token()?;
+        if token != expected {
+            return Err(Error::Parse(format!("expected {}, found {}", expected, token)));
+        }
+        Ok(())
+    }
+
+    // Fails on anything left over after the last value
+    pub fn finish(mut self) -> Result<()> {
+        match self.tokens.next() {
+            Some(token) => Err(Error::Parse(format!("unexpected trailing {}", token))),
+            None => Ok(()),
+        }
+    }
+}
+
+// Header naming what a file holds and which curve it belongs to
+pub fn write_header<E: Engine>(writer: &mut Writer, kind: &str) {
+    writer.token(kind);
+    writer.token(E::NAME);
+    writer.newline();
+}
+
+pub fn read_header<E: Engine>(reader: &mut Reader, kind: &str) -> Result<()> {
+    reader.expect(kind)?;
+    let curve = reader.token()?;
+    if curve != E::NAME {
+        return Err(Error::Parse(format!("{} is for {}, not {}", kind, curve, E::NAME)));
+    }
+    Ok(())
+}
+
+pub fn to_text<T: Encode>(value: &T) -> String {
+    let mut writer = Writer::new();
+    value.encode(&mut writer);
+    writer.finish()
+}
+
+pub fn from_text<T: Encode>(text: &str) -> Result<T> {
+    let mut reader = Reader::new(text);
+    let value = T::decode(&mut reader)?;
+    reader.finish()?;
+    Ok(value)
+}
+
+// Decimal integer, optionally negative, reduced into F so values of any size fit
+pub fn parse_field_element<F: Field>(token: &str) -> Result<F> {
+    let invalid = || Error::Parse(format!("invalid integer {}", token));
+    let (negative, digits) = match token.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, token),
+    };
+    if digits.is_empty() {
+        return Err(invalid());
+    }
+    let ten = F::from_u64(10);
+    let mut value = F::zero();
+    for digit in digits.chars() {
+        value = value * ten + F::from_u64(digit.to_digit(10).ok_or_else(invalid)? as u64);
+    }
+    Ok(if negative { -value } else { value })
+}
+
+// Whitespace-separated integers, e.g. a witness or the public inputs, # starting a comment
+pub fn parse_field_elements<F: Field>(text: &str) -> Result<Vec<F>> {
+    text.lines()
+        .flat_map(|line| line.split('#').next().unwrap_or("").split_whitespace())
+        .map(parse_field_element)
+        .collect()
+}
+
+impl Encode for usize {
+    fn encode(&self, writer: &mut Writer) {
+        writer.token(&self.to_string());
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        let token = reader.token()?;
+        token.parse().map_err(|_| Error::Parse(format!("invalid integer {}", token)))
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Encode for Fp<P, N> {
+    fn encode(&self, writer: &mut Writer) {
+        let hex: String = self.value().iter().rev().map(|limb| format!("{:016x}", limb)).collect();
+        writer.token(&hex);
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        let token = reader.token()?;
+        let invalid = || Error::Parse(format!("invalid field element {}", token));
+        if token.len() != 16 * N || !token.is_ascii() {
+            return Err(invalid());
+        }
+
+        let mut limbs = [0u64; N];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let end = token.len() - 16 * i;
+            *limb = u64::from_str_radix(&token[end - 16..end], 16).map_err(|_| invalid())?;
+        }
+        // Canonical values only, so every element has a single encoding
+        let below_modulus = limbs.iter().rev().lt(P::MODULUS.iter().rev());
+        if !below_modulus {
+            return Err(invalid());
+        }
+        Ok(Fp::from_limbs(limbs))
+    }
+}
+
+impl<P: Fp2Config> Encode for Fp2<P>
+where
+    P::Fp: Encode,
+{
+    fn encode(&self, writer: &mut Writer) {
+        self.c0.encode(writer);
+        self.c1.encode(writer);
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        Ok(Fp2::new(P::Fp::decode(reader)?, P::Fp::decode(reader)?))
+    }
+}
+
+// "inf" for the identity, otherwise x and y
+impl<C: CurveParams> Encode for Affine<C>
+where
+    C::Base: Encode,
+{
+    fn encode(&self, writer: &mut Writer) {
+        if self.is_identity() {
+            writer.token("inf");
+        } else {
+            self.x().encode(writer);
+            self.y().encode(writer);
+        }
+        writer.newline();
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        if reader.tokens.peek() == Some(&"inf") {
+            reader.token()?;
+            return Ok(Affine::identity());
+        }
+        let point = Affine::new(C::Base::decode(reader)?, C::Base::decode(reader)?);
+        if !point.is_on_curve() {
+            return Err(Error::Parse("point is not on the curve".to_string()));
+        }
+        Ok(point)
+    }
+}
+
+impl<C: CurveParams> Encode for Projective<C>
+where
+    C::Base: Encode,
+{
+    fn encode(&self, writer: &mut Writer) {
+        self.to_affine().encode(writer);
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        Ok(Affine::<C>::decode(reader)?.to_projective())
+    }
+}
+
+// Length first, then the elements
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, writer: &mut Writer) {
+        self.len().encode(writer);
+        writer.newline();
+        for item in self {
+            item.encode(writer);
+        }
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        let len = usize::decode(reader)?;
+        (0..len).map(|_| T::decode(reader)).collect()
+    }
+}
+
+impl<F: Field + Encode> Encode for Polynomial<F> {
+    fn encode(&self, writer: &mut Writer) {
+        self.coeffs().to_vec().encode(writer);
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        Ok(Polynomial::new(Vec::decode(reader)?))
+    }
+}
+
+// Width and height, then each row as its length and (column, value) pairs
+impl<F: Field + Encode> Encode for SparseMatrix<F> {
+    fn encode(&self, writer: &mut Writer) {
+        self.num_columns().encode(writer);
+        self.num_rows().encode(writer);
+        writer.newline();
+        for row in self.rows() {
+            row.len().encode(writer);
+            for (column, value) in row {
+                column.encode(writer);
+                value.encode(writer);
+            }
+            writer.newline();
+        }
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        let num_columns = usize::decode(reader)?;
+        let num_rows = usize::decode(reader)?;
+        let mut matrix = SparseMatrix::new(num_columns);
+        for _ in 0..num_rows {
+            let len = usize::decode(reader)?;
+            let row: Vec<(usize, F)> = (0..len)
+                .map(|_| Ok((usize::decode(reader)?, F::decode(reader)?)))
+                .collect::<Result<_>>()?;
+            matrix.push_row(row)?;
+        }
+        Ok(matrix)
+    }
+}
+
+impl<F: FftField + Encode> Encode for QAP<F> {
+    fn encode(&self, writer: &mut Writer) {
+        (self.t_val() as usize).encode(writer);
+        self.num_public().encode(writer);
+        writer.newline();
+        self.u().encode(writer);
+        self.v().encode(writer);
+        self.w().encode(writer);
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        let size = usize::decode(reader)?;
+        let domain = EvaluationDomain::new(size).ok_or(Error::DomainTooLarge(size))?;
+        let num_public = usize::decode(reader)?;
+        let u = SparseMatrix::decode(reader)?;
+        let v = SparseMatrix::decode(reader)?;
+        let w = SparseMatrix::decode(reader)?;
+        QAP::new(u, v, w, domain, num_public)
+    }
+}
+
+impl<E: Engine> Encode for VerifyingKey<E> {
+    fn encode(&self, writer: &mut Writer) {
+        write_header::<E>(writer, "verifying-key");
+        self.alpha_g1().encode(writer);
+        self.beta_g2().encode(writer);
+        self.gamma_g2().encode(writer);
+        self.delta_g2().encode(writer);
+        self.ic().to_vec().encode(writer);
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        read_header::<E>(reader, "verifying-key")?;
+        let vk = VerifyingKey::new(
+            Projective::decode(reader)?,
+            Projective::decode(reader)?,
+            Projective::decode(reader)?,
+            Projective::decode(reader)?,
+            Vec::decode(reader)?,
+        );
+        if vk.ic().is_empty() {
+            return Err(Error::Parse("verifying key has no IC point for the constant one".to_string()));
+        }
+        if !vk.is_valid() {
+            return Err(Error::Parse("verifying key point is not in the prime-order subgroup".to_string()));
+        }
+        Ok(vk)
+    }
+}
+
+impl<E: Engine> Encode for ProvingKey<E> {
+    fn encode(&self, writer: &mut Writer) {
+        write_header::<E>(writer, "proving-key");
+        self.vk().encode(writer);
+        self.qap().encode(writer);
+        self.beta_g1().encode(writer);
+        self.delta_g1().encode(writer);
+        self.tau_g1().to_vec().encode(writer);
+        self.tau_g2().to_vec().encode(writer);
+        self.l().to_vec().encode(writer);
+        self.h().to_vec().encode(writer);
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        read_header::<E>(reader, "proving-key")?;
+        let vk = VerifyingKey::decode(reader)?;
+        let qap = QAP::decode(reader)?;
+        ProvingKey::new(
+            qap,
+            vk,
+            Projective::decode(reader)?,
+            Projective::decode(reader)?,
+            Vec::decode(reader)?,
+            Vec::decode(reader)?,
+            Vec::decode(reader)?,
+            Vec::decode(reader)?,
+        )
+    }
+}
+
+impl<E: Engine> Encode for Proof<E> {
+    fn encode(&self, writer: &mut Writer) {
+        write_header::<E>(writer, "proof");
+        self.a().encode(writer);
+        self.b().encode(writer);
+        self.c().encode(writer);
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        read_header::<E>(reader, "proof")?;
+        Ok(Proof::new(
+            Projective::decode(reader)?,
+            Projective::decode(reader)?,
+            Projective::decode(reader)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::Bls12_381;
+    use crate::bn254::{Bn254, Fq, G1Affine};
+    use crate::r1cs::R1CS;
+
+    // w1 = w2 * w3, with w1 public
+    fn keys_and_proof<E: Engine>() -> (ProvingKey<E>, Proof<E>) {
+        let r1cs: R1CS<E::Fr> = "variables 4\npublic 1\nw2 * w3 = w1\n".parse().unwrap();
+        let pk = crate::setup::<E, _>(r1cs).unwrap();
+        let witness = [1, 6, 2, 3].map(E::Fr::from_u64);
+        let proof = crate::prove_witness(&pk, &witness).unwrap();
+        (pk, proof)
+    }
+
+    // Canonical encodings, so decoding and re-encoding gives back the same text
+    fn round_trip<T: Encode>(value: &T) -> T {
+        let text = to_text(value);
+        let decoded: T = from_text(&text).unwrap();
+        assert_eq!(to_text(&decoded), text);
+        decoded
+    }
+
+    fn keys_and_proofs_round_trip<E: Engine>() {
+        let (pk, proof) = keys_and_proof::<E>();
+        let decoded_pk = round_trip(&pk);
+        let vk = round_trip(pk.vk());
+        let decoded_proof = round_trip(&proof);
+        assert_eq!(decoded_pk.vk(), pk.vk());
+        assert_eq!(vk, *pk.vk());
+        assert_eq!(decoded_proof, proof);
+
+        // The decoded keys still prove and verify
+        let proof = crate::prove_witness(&decoded_pk, &[1, 6, 2, 3].map(E::Fr::from_u64)).unwrap();
+        assert!(crate::verify(&vk.prepare().unwrap(), &[E::Fr::from_u64(6)], &proof).is_ok());
+    }
+
+    #[test]
+    fn bn254_round_trip() {
+        keys_and_proofs_round_trip::<Bn254>();
+    }
+
+    #[test]
+    fn bls12_381_round_trip() {
+        keys_and_proofs_round_trip::<Bls12_381>();
+    }
+
+    #[test]
+    fn rejects_non_canonical_field_elements() {
+        // The modulus itself, the non-canonical encoding of zero
+        let modulus = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+        assert!(matches!(from_text::<Fq>(modulus), Err(Error::Parse(_))));
+        let below = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46";
+        assert_eq!(from_text::<Fq>(below).unwrap(), -Fq::one());
+        // Short and non-hex tokens
+        assert!(matches!(from_text::<Fq>(&below[1..]), Err(Error::Parse(_))));
+        assert!(matches!(from_text::<Fq>(&below.replace('4', "g")), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn rejects_points_off_the_curve() {
+        let g = G1Affine::generator();
+        let on_curve = to_text(&g);
+        assert_eq!(from_text::<G1Affine>(&on_curve).unwrap(), g);
+        // The generator is (1, 2); (1, 3) is not on y^2 = x^3 + 3
+        let off_curve = format!("{} {}", to_text(&Fq::one()), to_text(&Fq::from_u64(3)));
+        assert!(matches!(from_text::<G1Affine>(&off_curve), Err(Error::Parse(_))));
+        assert!(matches!(from_text::<G1Affine>("inf"), Ok(point) if point.is_identity()));
+    }
+
+    #[test]
+    fn rejects_keys_and_proofs_for_another_curve() {
+        let (pk, proof) = keys_and_proof::<Bn254>();
+        let wrong_curve = |error: Error| matches!(error, Error::Parse(message) if message.ends_with("is for bn254, not bls12_381"));
+        assert!(wrong_curve(from_text::<ProvingKey<Bls12_381>>(&to_text(&pk)).err().unwrap()));
+        assert!(wrong_curve(from_text::<VerifyingKey<Bls12_381>>(&to_text(pk.vk())).unwrap_err()));
+        assert!(wrong_curve(from_text::<Proof<Bls12_381>>(&to_text(&proof)).unwrap_err()));
+    }
+}
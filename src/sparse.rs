@@ -0,0 +1,116 @@
+use crate::error::{Error, Result};
+use crate::field::Field;
+
+// Compressed sparse rows: row i holds entries[row_starts[i]..row_starts[i + 1]],
+// sorted by column, with no zero or repeated columns
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMatrix<F: Field> {
+    num_columns: usize,
+    row_starts: Vec<usize>,
+    entries: Vec<(usize, F)>,
+}
+
+impl<F: Field> SparseMatrix<F> {
+    pub fn new(num_columns: usize) -> Self {
+        SparseMatrix { num_columns, row_starts: vec![0], entries: vec![] }
+    }
+
+    // Appends a row from (column, value) terms, summing repeated columns
+    pub fn push_row(&mut self, terms: impl IntoIterator<Item = (usize, F)>) -> Result<()> {
+        let mut row: Vec<(usize, F)> = terms.into_iter().collect();
+        if let Some(&(column, _)) = row.iter().find(|(column, _)| *column >= self.num_columns) {
+            return Err(Error::DimensionMismatch { expected: self.num_columns, found: column + 1 });
+        }
+        row.sort_by_key(|&(column, _)| column);
+
+        let mut merged: Vec<(usize, F)> = Vec::with_capacity(row.len());
+        for (column, value) in row {
+            match merged.last_mut() {
+                Some(last) if last.0 == column => last.1 = last.1 + value,
+                _ => merged.push((column, value)),
+            }
+        }
+        self.entries.extend(merged.into_iter().filter(|(_, value)| !value.is_zero()));
+        self.row_starts.push(self.entries.len());
+        Ok(())
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.row_starts.len() - 1
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    // Non-zero entries, the number stored
+    pub fn num_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn row(&self, i: usize) -> &[(usize, F)] {
+        &self.entries[self.row_starts[i]..self.row_starts[i + 1]]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[(usize, F)]> {
+        (0..self.num_rows()).map(move |i| self.row(i))
+    }
+
+    // M·v, touching only the stored entries
+    pub fn mul_vector(&self, vector: &[F]) -> Result<Vec<F>> {
+        if vector.len() != self.num_columns {
+            return Err(Error::DimensionMismatch { expected: self.num_columns, found: vector.len() });
+        }
+        Ok(self.rows()
+            .map(|row| row.iter().fold(F::zero(), |acc, &(column, value)| acc + value * vector[column]))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::Fr;
+
+    fn fr(value: u64) -> Fr {
+        Fr::from_u64(value)
+    }
+
+    #[test]
+    fn push_row_merges_duplicate_columns() {
+        let mut matrix = SparseMatrix::new(4);
+        matrix.push_row([(2, fr(3)), (0, fr(1)), (2, fr(4))]).unwrap();
+        assert_eq!(matrix.row(0), &[(0, fr(1)), (2, fr(7))]);
+    }
+
+    #[test]
+    fn push_row_drops_zero_entries() {
+        let mut matrix = SparseMatrix::new(4);
+        matrix.push_row([(1, fr(5)), (1, -fr(5)), (3, Fr::zero()), (2, fr(1))]).unwrap();
+        assert_eq!(matrix.row(0), &[(2, fr(1))]);
+        matrix.push_row([]).unwrap();
+        assert_eq!(matrix.num_rows(), 2);
+        assert_eq!(matrix.num_entries(), 1);
+        assert!(matrix.row(1).is_empty());
+    }
+
+    #[test]
+    fn push_row_rejects_out_of_range_columns() {
+        let mut matrix = SparseMatrix::new(3);
+        let result = matrix.push_row([(0, fr(1)), (5, fr(2))]);
+        assert!(matches!(result, Err(Error::DimensionMismatch { expected: 3, found: 6 })));
+        assert_eq!(matrix.num_rows(), 0);
+    }
+
+    #[test]
+    fn mul_vector_matches_dense_product() {
+        // [[1, 0, 2], [0, 0, 0], [0, 3, 4]]
+        let mut matrix = SparseMatrix::new(3);
+        matrix.push_row([(0, fr(1)), (2, fr(2))]).unwrap();
+        matrix.push_row([]).unwrap();
+        matrix.push_row([(1, fr(3)), (2, fr(4))]).unwrap();
+        let product = matrix.mul_vector(&[fr(5), fr(6), fr(7)]).unwrap();
+        assert_eq!(product, vec![fr(19), Fr::zero(), fr(46)]);
+        assert!(matches!(matrix.mul_vector(&[fr(1)]), Err(Error::DimensionMismatch { expected: 3, found: 1 })));
+    }
+}
@@ -1,106 +1,64 @@
-use crate::{field::{FieldElement64, MODULUS64}, qap::falling_factorial};
-
-
-pub fn trustedsetup(x: u64) -> [Vec<FieldElement64>; 3] {
-    let seed = FieldElement64::random();
-    let g1 = FieldElement64::new(generator().1);
-    let g2 = FieldElement64::new(generator().1);
-    let t_tau = falling_factorial(seed, x);
-    let srs1 = srs_creator(x, seed, g1);
-    let srs2 = srs_creator(x, seed, g2);
-    let srs3 = srs_creator(x, seed, g1 * t_tau);
-    println!("Trusted setup done!");
-    [srs1, srs2, srs3]
+use crate::curve::{CurveParams, Projective};
+use crate::engine::Engine;
+use crate::error::{Error, Result};
+use crate::keys::{ProvingKey, VerifyingKey};
+use crate::qap::QAP;
+use crate::domain::EvaluationDomain;
+use crate::field::{Field, PrimeField};
+
+// CRS for an already synthesized QAP; circuits enter through groth16::setup, which builds it
+pub fn trustedsetup<E: Engine>(qap: &QAP<E::Fr>) -> Result<ProvingKey<E>> {
+    // Toxic waste
+    let alpha = E::Fr::random();
+    let beta = E::Fr::random();
+    let gamma = E::Fr::random();
+    let delta = E::Fr::random();
+    let tau = E::Fr::random();
+    let gamma_inv = gamma.inverse().ok_or(Error::NonInvertible)?;
+    let delta_inv = delta.inverse().ok_or(Error::NonInvertible)?;
+
+    let g1 = Projective::<E::G1>::generator();
+    let g2 = Projective::<E::G2>::generator();
+    let n = qap.t_val();
+    let domain = EvaluationDomain::<E::Fr>::new(n as usize).ok_or(Error::DomainTooLarge(n as usize))?;
+    let t_tau = domain.evaluate_vanishing_polynomial(tau);
+
+    // beta * u_i(tau) + alpha * v_i(tau) + w_i(tau) for every variable
+    let (u, v, w) = qap.evaluate_at(tau);
+    let terms: Vec<E::Fr> = u.iter()
+        .zip(v.iter())
+        .zip(w.iter())
+        .map(|((&u, &v), &w)| beta * u + alpha * v + w)
+        .collect();
+    // The constant one and the public inputs go over gamma, the rest over delta
+    let (public, private) = terms.split_at(qap.num_public() + 1);
+
+    let vk = VerifyingKey::new(
+        g1 * alpha,
+        g2 * beta,
+        g2 * gamma,
+        g2 * delta,
+        public.iter().map(|&term| g1 * (term * gamma_inv)).collect(),
+    );
+    ProvingKey::new(
+        qap.clone(),
+        vk,
+        g1 * beta,
+        g1 * delta,
+        srs_creator(n, tau, g1),
+        srs_creator(n, tau, g2),
+        private.iter().map(|&term| g1 * (term * delta_inv)).collect(),
+        srs_creator(n - 1, tau, g1 * (t_tau * delta_inv)),
+    )
 }
 
-pub fn srs_creator(len: u64, tau: FieldElement64, g: FieldElement64) -> Vec<FieldElement64> {
-    let mut srs: Vec<FieldElement64> = vec![g];
+// [g, tau * g, ..., tau^(len - 1) * g]
+pub fn srs_creator<C: CurveParams, F: PrimeField>(len: u64, tau: F, g: Projective<C>) -> Vec<Projective<C>> {
+    let mut srs: Vec<Projective<C>> = Vec::with_capacity(len as usize);
+    let mut current = g;
     for _ in 0..len {
-        srs.push(srs.last().unwrap().clone() * tau);
+        srs.push(current);
+        current = current * tau;
     }
     srs
 }
-
-
-pub fn mod_exp(base: u64, mut exp: u64, modulus: u64) -> u64 {
-    let mut result = 1u64;
-    let mut base = base % modulus; 
-    
-    while exp > 0 {
-        if exp % 2 == 1 {
-            result = ((result as u128 * base as u128) % modulus as u128) as u64;
-        }
-
-        base = ((base as u128 * base as u128) % modulus as u128) as u64;
-        exp /= 2;
-    }
-    result
-}
-
-pub fn mod_sqrt(a: u64, p: u64) -> Option<u64> {
-    if a == 0 {
-        return Some(0);
-    }
-
-    if mod_exp(a, (p - 1) / 2, p) != 1 {
-        return None;
-    }
-
-    // Tonelli-Shanks algorithm
-    let mut q = p - 1;
-    let mut s = 0;
-    while q % 2 == 0 {
-        q /= 2;
-        s += 1;
-    }
-    let mut z = 2;
-    while mod_exp(z, (p - 1) / 2, p) == 1 {
-        z += 1;
-    }
-
-    let mut m = s;
-    let mut c = mod_exp(z, q, p);
-    let mut t = mod_exp(a, q, p);
-    let mut r = mod_exp(a, (q + 1) / 2, p);
-
-    while t != 0 && t != 1 {
-        let mut i = 0;
-        let mut temp = t;
-        while temp != 1 {
-            temp = mod_exp(temp, 2, p);
-            i += 1;
-        }
-
-        let b = mod_exp(c, 2u64.pow((m - i - 1) as u32), p);
-        m = i;
-        c = mod_exp(b, 2, p);
-
-        t = mod_exp(t, 2u64.pow(i as u32), p);
-        
-        r = ((r as u128 * b as u128) % p as u128) as u64;
-    }
-
-    if t == 0 {
-        None
-    } else {
-        Some(r)
-    }
-}
-
-// y^2 = x^3 + 3 (mod p)
-pub fn generator() -> (u64, u64) {
-    for x in 1..MODULUS64 {
-        let rhs = (mod_exp(x, 3, MODULUS64) + 3) % MODULUS64;
-
-        if let Some(y) = mod_sqrt(rhs, MODULUS64) {
-            let y_squared = ((y as u128 * y as u128) % MODULUS64 as u128) as u64;
-
-            if y_squared == rhs {
-                return (x, y);
-            }
-        }
-        // println!("x = {}", x);
-    }
-
-    panic!("No generator found");
-}
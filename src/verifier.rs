@@ -0,0 +1,30 @@
+use crate::curve::Projective;
+use crate::engine::Engine;
+use crate::error::{Error, Result};
+use crate::keys::PreparedVerifyingKey;
+use crate::proof::Proof;
+
+// e(A, B) * e(IC, -gamma) * e(C, -delta) = e(alpha, beta),
+// with IC = ic_0 + sum x_i * ic_i over the public inputs x
+pub fn verify_proof<E: Engine>(pvk: &PreparedVerifyingKey<E>, public_inputs: &[E::Fr], proof: &Proof<E>) -> Result<()> {
+    if !proof.is_valid() {
+        return Err(Error::MalformedProof);
+    }
+    // ic_0 for the constant one, then one point per public input
+    let (ic_0, ic_public) = pvk.ic().split_first().ok_or(Error::DimensionMismatch { expected: 1, found: 0 })?;
+    if public_inputs.len() != ic_public.len() {
+        return Err(Error::DimensionMismatch { expected: ic_public.len(), found: public_inputs.len() });
+    }
+    let ic = *ic_0 + Projective::msm(ic_public, public_inputs)?;
+
+    let lhs = E::multi_pairing(&[
+        (&proof.a().to_affine(), &E::prepare_g2(&proof.b().to_affine())?),
+        (&ic.to_affine(), pvk.neg_gamma_g2()),
+        (&proof.c().to_affine(), pvk.neg_delta_g2()),
+    ]);
+    if lhs != pvk.alpha_g1_beta_g2() {
+        return Err(Error::PairingCheckFailed);
+    }
+    Ok(())
+}
+